@@ -0,0 +1,3 @@
+use aoc2021_day_1::Day1;
+
+aoc_lib::aoc_bench!(Day1);
@@ -0,0 +1,4 @@
+use aoc2021_day_1::Day1;
+use aoc_lib::*;
+
+aoc_setup!(Day1, sample 1: 7, sample 2: 5, part 1: 1759, part 2: 1805);
@@ -0,0 +1,3 @@
+use aoc2021_day_10::Day10;
+
+aoc_lib::aoc_bench!(Day10);
@@ -1,18 +1,17 @@
 use aoc_lib::*;
 
-aoc_setup!(Day10, sample 1: 26397, sample 2: 288957, part 1: 344193, part 2: 3241238967);
-
 pub struct Day10;
 
 impl AdventOfCode for Day10 {
     type Input = String;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(s: &str) -> Self::Input {
         String::from(s)
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
         input
             .lines()
             .map(|l| match parse(l) {
@@ -26,7 +25,7 @@ impl AdventOfCode for Day10 {
             .sum()
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
         let mut scores = input
             .lines()
             .filter_map(|line| parse_recursive_counting_closing(line.as_bytes(), 0).map(|x| x.2))
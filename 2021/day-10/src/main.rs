@@ -0,0 +1,4 @@
+use aoc2021_day_10::Day10;
+use aoc_lib::*;
+
+aoc_setup!(Day10, sample 1: 26397, sample 2: 288957, part 1: 344193, part 2: 3241238967);
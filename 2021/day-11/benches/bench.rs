@@ -0,0 +1,3 @@
+use aoc2021_day_11::Day11;
+
+aoc_lib::aoc_bench!(Day11);
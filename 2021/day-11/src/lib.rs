@@ -0,0 +1,71 @@
+use aoc_lib::{utils::Field2D, *};
+
+#[derive(Clone)]
+pub struct Input {
+    // NOTE: very minor improvement (noise?) to part 1 and 2 if data type is u32
+    pub data: Field2D<u8>,
+}
+
+impl Input {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Input {
+            data: Field2D::parse(input).map_err(|e| e.located(input))?,
+        })
+    }
+
+    pub fn step(&mut self) -> usize {
+        // NOTE: could look into trying to do this in a single pass
+        (0..self.data.data.len()).for_each(|idx| self.data.data[idx] += 1);
+        (0..self.data.data.len()).for_each(|idx| self.flash_recursive(idx));
+
+        self.data.data.iter().filter(|&&x| x == 0).count()
+    }
+
+    fn flash_recursive(&mut self, idx: usize) {
+        if self.data.data[idx] > 9 {
+            self.data.data[idx] = 0;
+
+            self.data
+                .eight_neighbour_indices(idx)
+                .into_iter()
+                .flatten()
+                .for_each(|n| {
+                    if self.data.data[n] != 0 {
+                        self.data.data[n] += 1;
+                        self.flash_recursive(n);
+                    }
+                })
+        }
+    }
+}
+
+pub struct Day11;
+
+impl TryAdventOfCode for Day11 {
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = ParseError;
+
+    fn try_parse_input(s: &str) -> Result<Self::Input, Self::Error> {
+        Self::Input::parse(s)
+    }
+
+    fn try_solve_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        let mut input = input.clone();
+        Ok((0..100).fold(0, |mut acc, _| {
+            acc += input.step();
+            acc
+        }))
+    }
+
+    fn try_solve_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        let mut input = input.clone();
+        Ok((0..usize::MAX)
+            .find_map(|i| match input.step() {
+                100 => Some(i + 1),
+                _ => None,
+            })
+            .unwrap())
+    }
+}
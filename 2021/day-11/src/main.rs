@@ -0,0 +1,4 @@
+use aoc2021_day_11::Day11;
+use aoc_lib::*;
+
+aoc_setup!(Day11, sample 1: 1656, sample 2: 195, part 1: 1785, part 2: 354);
@@ -0,0 +1,3 @@
+use aoc2021_day_12::Day12;
+
+aoc_lib::aoc_bench!(Day12);
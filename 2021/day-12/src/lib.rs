@@ -4,8 +4,6 @@ use aoc_lib::*;
 use petgraph::{graph::NodeIndex, Graph, Undirected};
 use tinyvec::{array_vec, ArrayVec};
 
-aoc_setup!(Day12, sample 1: 10, sample 2: 36, part 1: 3497, part 2: 93686);
-
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct Node(u16);
 
@@ -13,20 +11,20 @@ impl Node {
     pub const START: Self = Node(0);
     pub const END: Self = Node(1);
 
-    pub fn parse(label: &str) -> Self {
+    pub fn parse(label: &str) -> Result<Self, ParseError> {
         match label {
-            "start" => Self::START,
-            "end" => Self::END,
-            label if label.len() == 2 => {
-                Node(u16::from_le_bytes(label.as_bytes().try_into().unwrap()))
-            }
+            "start" => Ok(Self::START),
+            "end" => Ok(Self::END),
+            label if label.len() == 2 => Ok(Node(u16::from_le_bytes(
+                label.as_bytes().try_into().unwrap(),
+            ))),
             label if label.len() == 1 => Self::parse(format!("{}{}", label, label).as_str()),
-            _ => panic!("Invalid node label: {}", label),
+            _ => Err(ParseError::new(label, "a cave label of 1 or 2 characters")),
         }
     }
 
     pub const fn can_visit_multiple_times(&self) -> bool {
-        matches!((self.0 & 0xFF) as u8, b'A'..=b'Z')
+        ((self.0 & 0xFF) as u8).is_ascii_uppercase()
     }
 }
 
@@ -59,24 +57,34 @@ impl From<Node> for NodeIndex {
 
 pub struct Day12;
 
-impl AdventOfCode for Day12 {
+impl TryAdventOfCode for Day12 {
     type Input = Graph<Node, (), Undirected>;
-    type Output = usize;
-
-    fn parse_input(s: &str) -> Self::Input {
-        Self::Input::from_edges(s.lines().map(|line| {
-            let (a, b) = line.split_once("-").unwrap();
-            (Node::parse(a), Node::parse(b))
-        }))
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = ParseError;
+
+    fn try_parse_input(s: &str) -> Result<Self::Input, Self::Error> {
+        let edges = s
+            .lines()
+            .map(|line| {
+                let (a, b) = line
+                    .split_once('-')
+                    .ok_or_else(|| ParseError::new(line, "two caves separated by '-'"))?;
+                Ok((Node::parse(a)?, Node::parse(b)?))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()
+            .map_err(|e| e.located(s))?;
+
+        Ok(Self::Input::from_edges(edges))
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
-        walk_edge_1(input, array_vec!(Node::START.into()))
+    fn try_solve_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(walk_edge_1(input, array_vec!(Node::START.into())))
     }
 
     #[allow(clippy::useless_conversion)] // clippy bug?
-    fn solve_2(input: &Self::Input) -> Self::Output {
-        walk_edge_2(input, array_vec!(Node::START.into()), false)
+    fn try_solve_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(walk_edge_2(input, array_vec!(Node::START.into()), false))
     }
 }
 
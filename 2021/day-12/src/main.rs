@@ -0,0 +1,14 @@
+use aoc2021_day_12::Day12;
+use aoc_lib::*;
+
+aoc_setup!(
+    Day12,
+    sample 1: 10,
+    sample 2: 36,
+    part 1: 3497,
+    part 2: 93686,
+    sample "sample2.txt" 1: 19,
+    sample "sample2.txt" 2: 103,
+    sample "sample3.txt" 1: 226,
+    sample "sample3.txt" 2: 3509
+);
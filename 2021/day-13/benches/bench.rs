@@ -0,0 +1,3 @@
+use aoc2021_day_13::Day13;
+
+aoc_lib::aoc_bench!(Day13);
@@ -1,10 +1,6 @@
-use aoc_lib::{utils::Field2D, *};
-use either::Either;
+use aoc_lib::{utils::InfiniteGrid, *};
 use tinyvec::{array_vec, ArrayVec};
 
-// sample 2: either::Right("O".into())?
-aoc_setup!(Day13, sample 1: either::Left(17), part 1: either::Left(785), part 2: either::Right("FJAHJGAH".into()));
-
 #[derive(Clone, Copy)]
 pub enum Fold {
     AlongX(usize),
@@ -21,7 +17,8 @@ pub struct Day13;
 
 impl AdventOfCode for Day13 {
     type Input = (Vec<(usize, usize)>, ArrayVec<[Fold; 12]>);
-    type Output = Either<usize, String>;
+    type Output1 = usize;
+    type Output2 = String;
 
     fn parse_input(s: &str) -> Self::Input {
         let mut points = vec![];
@@ -49,7 +46,7 @@ impl AdventOfCode for Day13 {
         (points, folds)
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
         let fold = input.1[0];
         let mut hashmap = rustc_hash::FxHashSet::default();
         hashmap.reserve(input.0.len());
@@ -62,45 +59,33 @@ impl AdventOfCode for Day13 {
             }),
         };
 
-        Either::Left(hashmap.len())
+        hashmap.len()
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
-        let (width, height) = input
-            .0
-            .iter()
-            .fold((0, 0), |(w, h), &(x, y)| (w.max(x + 1), h.max(y + 1)));
-
-        let (width, height) = input
-            .1
-            .iter()
-            .fold((width, height), |(w, h), &fold| match fold {
-                Fold::AlongX(fold_idx) => (fold_idx, h),
-                Fold::AlongY(fold_idx) => (w, fold_idx),
-            });
-
-        let mut field = Field2D::new(width, height, false);
-
-        input
-            .0
-            .iter()
-            .map(|&(x, y)| {
-                input.1.iter().fold((x, y), |(x, y), &fold| match fold {
-                    Fold::AlongX(fold_idx) => (if x > fold_idx { fold_idx * 2 - x } else { x }, y),
-                    Fold::AlongY(fold_idx) => (x, if y > fold_idx { fold_idx * 2 - y } else { y }),
-                })
-            })
-            .for_each(|(x, y)| field[(x, y)] = true);
-
-        Either::Right(
-            (0..(field.stride / 5))
-                .map(|i| (b'A' + ocr(&field, i)) as char)
-                .collect::<String>(),
-        )
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
+        let paper = fold_paper(input);
+
+        // letters are 4 dots wide, with an empty column after each
+        let letters = paper.x_range().max().map_or(0, |max_x| max_x / 5 + 1);
+        (0..letters).map(|i| ocr(&paper, i)).collect()
+    }
+}
+
+/// Applies every fold, marking where the dots end up.
+fn fold_paper((points, folds): &<Day13 as AdventOfCode>::Input) -> InfiniteGrid<bool> {
+    let mut paper = InfiniteGrid::new(false);
+    for &(x, y) in points {
+        let (x, y) = folds.iter().fold((x, y), |(x, y), &fold| match fold {
+            Fold::AlongX(fold_idx) => (if x > fold_idx { fold_idx * 2 - x } else { x }, y),
+            Fold::AlongY(fold_idx) => (x, if y > fold_idx { fold_idx * 2 - y } else { y }),
+        });
+        paper[(x as isize, y as isize)] = true;
     }
+    paper
 }
 
-fn ocr(field: &Field2D<bool>, index: usize) -> u8 {
+/// Reads the letter at the given position, or `?` if the dots there don't form one.
+fn ocr(paper: &InfiniteGrid<bool>, index: isize) -> char {
     let start_x = 5 * index;
 
     LETTER_MAP
@@ -108,15 +93,10 @@ fn ocr(field: &Field2D<bool>, index: usize) -> u8 {
         .enumerate()
         .filter_map(|(i, &l)| l.map(|letter| (i, letter)))
         .find(|&(_, letter)| {
-            (0..6).all(|y| {
-                let idx = start_x + y * field.stride;
-                let slice: [bool; 4] = field.data[idx..idx + 4].try_into().unwrap();
-
-                slice == letter[y]
-            })
+            (0..6)
+                .all(|y| (0..4).all(|x| paper[(start_x + x, y)] == letter[y as usize][x as usize]))
         })
-        .unwrap()
-        .0 as u8
+        .map_or('?', |(i, _)| (b'A' + i as u8) as char)
 }
 
 const fn parse_letter(data: [u8; 6]) -> Option<Letter> {
@@ -169,3 +149,18 @@ const LETTER_MAP: [Option<Letter>; 26] = [
     None,
     parse_letter([0b1111, 0b0001, 0b0010, 0b0100, 0b1000, 0b1111]),
 ];
+
+#[test]
+fn test_sample_folds_into_square() {
+    let paper = fold_paper(&Day13::parse_input(include_str!("../sample.txt")));
+    let rendered = paper
+        .y_range()
+        .map(|y| {
+            let row = paper
+                .x_range()
+                .map(|x| if paper[(x, y)] { '#' } else { '.' });
+            row.collect::<String>() + "\n"
+        })
+        .collect::<String>();
+    assert_eq!("#####\n#...#\n#...#\n#...#\n#####\n", rendered);
+}
@@ -0,0 +1,5 @@
+use aoc2021_day_13::Day13;
+use aoc_lib::*;
+
+// the sample folds into a square instead of letters, see test_sample_folds_into_square
+aoc_setup!(Day13, sample 1: 17, part 1: 785, part 2: "FJAHJGAH");
@@ -0,0 +1,3 @@
+use aoc2021_day_14::Day14;
+
+aoc_lib::aoc_bench!(Day14);
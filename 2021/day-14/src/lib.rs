@@ -3,8 +3,6 @@ use std::ops::Add;
 use aoc_lib::*;
 use tinyvec::ArrayVec;
 
-aoc_setup!(Day14, sample 1: 1588, sample 2: 2188189693529, part 1: 2967, part 2: 3692219987038);
-
 // TODO: could be stack-based hashmap. may need to make size u32
 #[derive(Default, Clone, Debug)]
 struct NumSet([u64; 26]);
@@ -47,7 +45,8 @@ pub struct Day14;
 
 impl AdventOfCode for Day14 {
     type Input = InputData;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(s: &str) -> Self::Input {
         let mut lines = s.lines();
@@ -69,13 +68,13 @@ impl AdventOfCode for Day14 {
         (template, pairs)
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
         // let start_pairs = input.0.as_slice().windows(2).map(|w| (w[0], w[1]));
 
         run(input, 10)
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
         run(input, 40)
     }
 }
@@ -0,0 +1,4 @@
+use aoc2021_day_14::Day14;
+use aoc_lib::*;
+
+aoc_setup!(Day14, sample 1: 1588, sample 2: 2188189693529, part 1: 2967, part 2: 3692219987038);
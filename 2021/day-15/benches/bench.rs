@@ -0,0 +1,3 @@
+use aoc2021_day_15::Day15;
+
+aoc_lib::aoc_bench!(Day15);
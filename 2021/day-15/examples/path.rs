@@ -0,0 +1,28 @@
+//! Prints the path with the lowest total risk through the cave on stdin.
+//!
+//! Usage: `cargo run -p aoc2021-day-15 --example path [-- --full] < input.txt`
+
+use std::process::exit;
+
+use aoc2021_day_15::Day15;
+use aoc_lib::*;
+
+fn main() {
+    let full = Args::from_env().take_flag("full");
+    let input = read_stdin().unwrap_or_else(|e| {
+        eprintln!("could not read stdin: {}", e);
+        exit(1);
+    });
+
+    let mut cave = Day15::parse(&input).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        exit(1);
+    });
+    if full {
+        cave = Day15::full_cave(&cave);
+    }
+
+    let path = Day15::lowest_risk_path(&cave);
+    print!("{}", Day15::render_path(&cave, &path.states));
+    println!("Total risk: {}", path.cost);
+}
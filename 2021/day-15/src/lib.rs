@@ -0,0 +1,100 @@
+use aoc_lib::{utils::Field2D, *};
+
+pub struct Day15;
+
+impl TryAdventOfCode for Day15 {
+    type Input = Field2D<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = ParseError;
+
+    fn try_parse_input(s: &str) -> Result<Self::Input, Self::Error> {
+        Field2D::parse(s).map_err(|e| e.located(s))
+    }
+
+    fn try_solve_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        // I guess we're doing dijkstra today bois
+        Ok(lowest_total_risk(input))
+    }
+
+    fn try_solve_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(lowest_total_risk(&Day15::full_cave(input)))
+    }
+}
+
+impl Day15 {
+    /// The full cave for part 2: the input tiled 5 times in both directions, with the risk
+    /// increasing by 1 for every tile to the right or down.
+    pub fn full_cave(input: &Field2D<u8>) -> Field2D<u8> {
+        let mut new_field = Field2D::new(input.width() * 5, input.height() * 5, 0u8);
+        input.data.iter().enumerate().for_each(|(i, &data)| {
+            (0..5)
+                .flat_map(|y| (0..5).map(move |x| (x, y)))
+                .for_each(|(x, y)| {
+                    let new_pos = (i % input.width()) + ((i / input.width()) * new_field.width());
+                    let offset_x = input.stride * x;
+                    let offset_y = (5 * input.data.len()) * y;
+                    let new_index = new_pos + offset_x + offset_y;
+                    let new_data = (((data as usize + x + y - 1) % 9) + 1) as u8;
+                    new_field.data[new_index] = new_data;
+                });
+        });
+        new_field
+    }
+
+    /// The path with the lowest total risk from the top left to the bottom right of the cave.
+    /// The states are indices into the cave, starting at 0.
+    pub fn lowest_risk_path(cave: &Field2D<u8>) -> search::Path<usize> {
+        let end = cave.data.len() - 1;
+        let successors = |node: usize| {
+            cave.neighbour_indices(node)
+                .into_iter()
+                .flatten()
+                .map(|edge| (edge, cave.data[edge] as usize))
+        };
+
+        search::dijkstra_dense(cave.data.len(), 0, successors, |node| node == end)
+            .expect("the end should be reachable")
+    }
+
+    /// Draws the cave with only the risk levels along `path` visible.
+    pub fn render_path(cave: &Field2D<u8>, path: &[usize]) -> String {
+        let mut on_path = vec![false; cave.data.len()];
+        path.iter().for_each(|&i| on_path[i] = true);
+
+        let mut rendered = String::with_capacity(cave.data.len() + cave.height());
+        for (i, &risk) in cave.data.iter().enumerate() {
+            rendered.push(match on_path[i] {
+                true => (b'0' + risk) as char,
+                false => '.',
+            });
+            if i % cave.stride == cave.stride - 1 {
+                rendered.push('\n');
+            }
+        }
+        rendered
+    }
+}
+
+fn lowest_total_risk(input: &Field2D<u8>) -> usize {
+    Day15::lowest_risk_path(input).cost
+}
+
+#[test]
+fn test_lowest_risk_path() {
+    let cave = Day15::parse(include_str!("../sample.txt")).unwrap();
+    let path = Day15::lowest_risk_path(&cave);
+    assert_eq!(40, path.cost);
+    assert_eq!(Some(&0), path.states.first());
+    assert_eq!(Some(&99), path.states.last());
+    assert_eq!(
+        path.cost,
+        path.states[1..]
+            .iter()
+            .map(|&i| cave.data[i] as usize)
+            .sum()
+    );
+
+    let rendered = Day15::render_path(&cave, &path.states);
+    assert!(rendered.starts_with("1.........\n1.........\n2136511..."));
+}
@@ -0,0 +1,4 @@
+use aoc2021_day_15::Day15;
+use aoc_lib::*;
+
+aoc_setup!(Day15, sample 1: 40, sample 2: 315, part 1: 562, part 2: 2874);
@@ -0,0 +1,3 @@
+use aoc2021_day_16::Day16;
+
+aoc_lib::aoc_bench!(Day16);
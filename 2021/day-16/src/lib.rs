@@ -4,8 +4,6 @@ use bumpalo::{
     Bump,
 };
 
-aoc_setup!(Day16, sample 1: 6, part 1: 967, part 2: 12883091136209);
-
 #[derive(Debug)]
 struct BitReader<'a> {
     data: &'a [u8],
@@ -159,52 +157,54 @@ impl<'a> PacketData<'a> {
     }
 }
 
+/// What both parts need from the packet tree. The tree itself lives in a bump arena that is dropped
+/// once these have been worked out.
+#[derive(Debug, Clone, Copy)]
+pub struct PacketSummary {
+    pub version_sum: u64,
+    pub value: u64,
+}
+
 pub struct Day16;
 
-impl AdventOfCode for Day16 {
+impl SharedAdventOfCode for Day16 {
     type Input = std::vec::Vec<u8>;
-    type Output = u64;
-
-    fn parse_input(s: &str) -> Self::Input {
-        hex::decode(s).unwrap()
+    type Shared = PacketSummary;
+    type Output1 = u64;
+    type Output2 = u64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Self::Input, Self::Error> {
+        hex::decode(s)
+            .map_err(|e| match e {
+                hex::FromHexError::InvalidHexCharacter { c, index } => {
+                    ParseError::new(&s[index..index + c.len_utf8()], "a hexadecimal digit")
+                }
+                _ => ParseError::new(s, "an even number of hexadecimal digits"),
+            })
+            .map_err(|e| e.located(s))
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
+    fn prepare(input: &Self::Input) -> Result<Self::Shared, Self::Error> {
         let bump = Bump::new();
-        let mut iter = BitReader::from(input);
-        let data = Packet::parse(&mut iter, &bump);
-        data.sum_versions() as u64
+        let packet = Packet::parse(&mut BitReader::from(input), &bump);
+        Ok(PacketSummary {
+            version_sum: packet.sum_versions() as u64,
+            value: packet.evaluate(),
+        })
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
-        let bump = Bump::new();
-        let mut iter = BitReader::from(input);
-        let data = Packet::parse(&mut iter, &bump);
-        data.evaluate()
+    fn part_1(_: &Self::Input, summary: &Self::Shared) -> Result<Self::Output1, Self::Error> {
+        Ok(summary.version_sum)
     }
-}
 
-macro_rules! add_test {
-    ($name:ident for $ident:ident: $source:literal becomes $target:literal) => {
-        #[test]
-        pub fn $name() {
-            let input = hex::decode($source).unwrap();
-            let output = Day16::$ident(&input);
-            assert_eq!(output, $target);
-        }
-    };
+    fn part_2(_: &Self::Input, summary: &Self::Shared) -> Result<Self::Output2, Self::Error> {
+        Ok(summary.value)
+    }
 }
 
-add_test!(part_1_extra_test_1 for solve_1: "8A004A801A8002F478" becomes 16);
-add_test!(part_1_extra_test_2 for solve_1: "620080001611562C8802118E34" becomes 12);
-add_test!(part_1_extra_test_3 for solve_1: "C0015000016115A2E0802F182340" becomes 23);
-add_test!(part_1_extra_test_4 for solve_1: "A0016C880162017C3686B18A3D4780" becomes 31);
-
-add_test!(part_2_extra_test_sum for solve_2: "C200B40A82" becomes 3);
-add_test!(part_2_extra_test_product for solve_2: "04005AC33890" becomes 54);
-add_test!(part_2_extra_test_min for solve_2: "880086C3E88112" becomes 7);
-add_test!(part_2_extra_test_max for solve_2: "CE00C43D881120" becomes 9);
-add_test!(part_2_extra_test_lt for solve_2: "D8005AC2A8F0" becomes 1);
-add_test!(part_2_extra_test_gt for solve_2: "F600BC2D8F" becomes 0);
-add_test!(part_2_extra_test_eq for solve_2: "9C005AC2F8F0" becomes 0);
-add_test!(part_2_extra_test_eq_compound for solve_2: "9C0141080250320F1802104A08" becomes 1);
+#[test]
+pub fn parse_error_points_at_invalid_digit() {
+    let error = Day16::parse("8A00G4A8").unwrap_err();
+    assert_eq!(Some(Position { line: 1, column: 5 }), error.position);
+}
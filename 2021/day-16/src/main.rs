@@ -0,0 +1,21 @@
+use aoc2021_day_16::Day16;
+use aoc_lib::*;
+
+aoc_setup!(
+    Day16,
+    sample 1: 6,
+    part 1: 967,
+    part 2: 12883091136209,
+    inline "8A004A801A8002F478" 1: 16,
+    inline "620080001611562C8802118E34" 1: 12,
+    inline "C0015000016115A2E0802F182340" 1: 23,
+    inline "A0016C880162017C3686B18A3D4780" 1: 31,
+    inline "C200B40A82" 2: 3,
+    inline "04005AC33890" 2: 54,
+    inline "880086C3E88112" 2: 7,
+    inline "CE00C43D881120" 2: 9,
+    inline "D8005AC2A8F0" 2: 1,
+    inline "F600BC2D8F" 2: 0,
+    inline "9C005AC2F8F0" 2: 0,
+    inline "9C0141080250320F1802104A08" 2: 1
+);
@@ -0,0 +1,3 @@
+use aoc2021_day_17::Day17;
+
+aoc_lib::aoc_bench!(Day17);
@@ -1,23 +1,10 @@
 use std::ops::Range;
 
-use aoc_lib::*;
-
-aoc_setup!(Day17, sample 1: 45, sample 2: 112, part 1: 5565, part 2: 2118);
-
-pub struct Vec2 {
-    x: isize,
-    y: isize,
-}
-
-impl Vec2 {
-    pub fn new(x: isize, y: isize) -> Vec2 {
-        Vec2 { x, y }
-    }
-}
+use aoc_lib::{utils::Point2, *};
 
 pub struct Input {
-    pub start: Vec2,
-    pub end: Vec2,
+    pub start: Point2<isize>,
+    pub end: Point2<isize>,
 }
 
 impl Input {
@@ -38,10 +25,10 @@ impl Input {
     }
 
     pub fn height(&self) -> usize {
-        (self.end.y - self.start.y).abs() as usize
+        (self.end.y - self.start.y).unsigned_abs()
     }
     pub fn width(&self) -> usize {
-        (self.end.y - self.start.y).abs() as usize
+        (self.end.y - self.start.y).unsigned_abs()
     }
 
     pub fn x_range(&self) -> Range<isize> {
@@ -55,7 +42,8 @@ pub struct Day17;
 
 impl AdventOfCode for Day17 {
     type Input = Input;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(s: &str) -> Self::Input {
         let (x_range, y_range) = s[15..].split_once(", y=").unwrap();
@@ -71,12 +59,12 @@ impl AdventOfCode for Day17 {
         );
 
         Input {
-            start: Vec2::new(x1.min(x2), y1.max(y2)),
-            end: Vec2::new(x1.max(x2), y1.min(y2)),
+            start: Point2::new(x1.min(x2), y1.max(y2)),
+            end: Point2::new(x1.max(x2), y1.min(y2)),
         }
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
         debug_assert!(
             !(input.x() < 0 && input.x2() > 0),
             "target area should not cross x axis, solution would be infinity"
@@ -94,7 +82,7 @@ impl AdventOfCode for Day17 {
         triangle_number(max_velocity_y as usize)
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
         debug_assert!(input.y() < 0, "assuming y < 0");
         debug_assert!(input.x() > 0, "assuming x > 0");
         // we need to find all shots that land in the target area. best start with bounds
@@ -108,7 +96,7 @@ impl AdventOfCode for Day17 {
                 .into_iter()
                 .find(|&x| x >= input.x() as usize)
                 .unwrap(),
-        ) as usize;
+        );
         let max_x_velocity = input.x2() as usize;
 
         (min_y_velocity..=max_y_velocity)
@@ -0,0 +1,4 @@
+use aoc2021_day_17::Day17;
+use aoc_lib::*;
+
+aoc_setup!(Day17, sample 1: 45, sample 2: 112, part 1: 5565, part 2: 2118);
@@ -0,0 +1,3 @@
+use aoc2021_day_18::Day18;
+
+aoc_lib::aoc_bench!(Day18);
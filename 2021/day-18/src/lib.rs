@@ -2,8 +2,6 @@ use std::{fmt::Display, ops::Add};
 
 use aoc_lib::*;
 
-aoc_setup!(Day18, sample 1: 4140, sample 2: 3993, part 1: 4184, part 2: 4731);
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SnailfishNumber(Vec<Token>); // TODO: try using tinyvec
 
@@ -112,7 +110,7 @@ impl SnailfishNumber {
         debug_assert!(self.0[index] != Token::Open);
 
         let value = self.0[index].unwrap_number();
-        let (left, right) = if value % 2 == 0 {
+        let (left, right) = if value.is_multiple_of(2) {
             (value / 2, value / 2)
         } else {
             (value / 2, value / 2 + 1)
@@ -189,8 +187,9 @@ impl Display for SnailfishNumber {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Token {
+    #[default]
     Open,
     Number(u8),
     Closed,
@@ -205,23 +204,18 @@ impl Token {
     }
 }
 
-impl Default for Token {
-    fn default() -> Self {
-        Token::Open
-    }
-}
-
 pub struct Day18;
 
 impl AdventOfCode for Day18 {
     type Input = Vec<SnailfishNumber>;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(s: &str) -> Self::Input {
         s.lines().map(SnailfishNumber::parse).collect()
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
         input
             .iter()
             .cloned() // eww
@@ -230,9 +224,8 @@ impl AdventOfCode for Day18 {
             .magnitude()
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
         (0..(input.len() * input.len()))
-            .into_iter()
             .map(|i| {
                 let a = i % input.len();
                 let b = i / input.len();
@@ -250,6 +243,8 @@ impl AdventOfCode for Day18 {
 macro_rules! magnitude_test {
     ($name:ident, $input:expr, $expected:expr) => {
         #[test]
+        // the expected values spell out 3 * left + 2 * right, even where a side is 1
+        #[allow(clippy::identity_op)]
         fn $name() {
             let input = SnailfishNumber::parse($input);
             let expected = $expected;
@@ -352,7 +347,7 @@ fn test_sum_example_basic() {
 fn test_sum_example_small() {
     let actual = ["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]", "[6,6]"]
         .into_iter()
-        .map(|s| SnailfishNumber::parse(s))
+        .map(SnailfishNumber::parse)
         .reduce(|acc, i| acc + i)
         .unwrap();
     let expected = SnailfishNumber::parse("[[[[5,0],[7,4]],[5,5]],[6,6]]");
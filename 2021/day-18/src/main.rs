@@ -0,0 +1,4 @@
+use aoc2021_day_18::Day18;
+use aoc_lib::*;
+
+aoc_setup!(Day18, sample 1: 4140, sample 2: 3993, part 1: 4184, part 2: 4731);
@@ -0,0 +1,3 @@
+use aoc2021_day_19::Day19;
+
+aoc_lib::aoc_bench!(Day19);
@@ -0,0 +1,154 @@
+use std::convert::Infallible;
+
+use aoc_lib::{
+    utils::{Point3, Rotation},
+    *,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use tinyvec::ArrayVec;
+
+fn parse_point(line: &str) -> Point3<i16> {
+    let mut parts = line.split(',');
+    let x = parts.next().unwrap().parse().unwrap();
+    let y = parts.next().unwrap().parse().unwrap();
+    let z = parts.next().unwrap().parse().unwrap();
+    Point3::new(x, y, z)
+}
+
+pub struct Day19;
+
+impl SharedAdventOfCode for Day19 {
+    type Input = Vec<Vec<Point3<i16>>>; // TODO: vec<vec3> prob has fixed lenght, can optimize for that
+    type Shared = CalculatePositionsTuple;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = Infallible;
+
+    fn parse(s: &str) -> Result<Self::Input, Self::Error> {
+        let mut input = vec![];
+        let mut iter = s.lines();
+        for i in 0.. {
+            let line = match iter.next() {
+                Some(line) => line,
+                None => break,
+            };
+            debug_assert_eq!(line, format!("--- scanner {} ---", i));
+
+            let mut vector_list = vec![];
+
+            for line in iter.by_ref() {
+                if line.is_empty() {
+                    break;
+                }
+
+                vector_list.push(parse_point(line));
+            }
+
+            input.push(vector_list);
+        }
+
+        Ok(input)
+    }
+
+    /// Aligning the scanners is by far the slowest part, and both parts need its result.
+    fn prepare(input: &Self::Input) -> Result<Self::Shared, Self::Error> {
+        Ok(calculate_positions(input))
+    }
+
+    fn part_1(_: &Self::Input, (_, beacons): &Self::Shared) -> Result<Self::Output1, Self::Error> {
+        Ok(beacons.len())
+    }
+
+    fn part_2(_: &Self::Input, (scanners, _): &Self::Shared) -> Result<Self::Output2, Self::Error> {
+        let positions = scanners
+            .iter()
+            .flatten()
+            .map(|(pos, _)| *pos)
+            .collect::<Vec<_>>();
+
+        Ok((0..positions.len())
+            .flat_map(|a| (a + 1..positions.len()).map(move |b| (a, b)))
+            .map(|(a, b)| positions[a].manhattan_distance(positions[b]))
+            .max()
+            .unwrap() as usize)
+    }
+}
+
+pub type CalculatePositionsTuple = (
+    ArrayVec<[Option<(Point3<i16>, Rotation)>; 33]>,
+    FxHashSet<Point3<i16>>,
+);
+
+fn calculate_positions(input: &[Vec<Point3<i16>>]) -> CalculatePositionsTuple {
+    // TODO: can just be [Option<Rotation>; 32] or tiny_vec::ArrayVec<[Option<Rotation>; 32]>
+    let mut scanners = ArrayVec::<[Option<(Point3<i16>, Rotation)>; 33]>::new();
+    scanners.insert(0, Some((Point3::default(), Rotation::IDENTITY)));
+    for _ in 1..input.len() {
+        scanners.push(None);
+    }
+
+    // create a collection with all known locations
+    let mut beacons = FxHashSet::default();
+    for &beacon in &input[0] {
+        beacons.insert(beacon);
+    }
+
+    let mut iteration_count = 0;
+    let mut reusable_hashmap = FxHashMap::default();
+    while !scanners.iter().all(|i| i.is_some()) {
+        for (i, list) in input.iter().enumerate() {
+            if scanners[i].is_some() {
+                continue;
+            }
+
+            // check if the list we're given matches at least 12 nodes in the known locations
+            for direction in Rotation::all() {
+                if let Some(found_root) =
+                    compare_sets(&beacons, list, direction, &mut reusable_hashmap)
+                {
+                    // store the position of this beacon
+                    scanners[i] = Some((found_root, direction));
+
+                    // store all newly found points
+                    for new_point in list {
+                        beacons.insert(found_root + direction.apply(*new_point));
+                    }
+
+                    break;
+                }
+            }
+        }
+
+        iteration_count += 1;
+        if iteration_count > input.len() {
+            panic!("Too many iterations");
+        }
+    }
+
+    (scanners, beacons)
+}
+
+fn compare_sets(
+    known_beacons: &FxHashSet<Point3<i16>>,
+    other: &[Point3<i16>],
+    other_direction: Rotation,
+    found_offsets: &mut FxHashMap<Point3<i16>, i32>,
+) -> Option<Point3<i16>> {
+    // this hashmap grows to 1000-2000 items, does not fit on the heap
+    found_offsets.clear();
+
+    for &real_beacon_location in known_beacons {
+        for &v in other {
+            let expected_root_location = real_beacon_location - other_direction.apply(v);
+            let entry = found_offsets.entry(expected_root_location).or_insert(0);
+            *entry += 1;
+
+            // cheeky early exit
+            if *entry >= 12 {
+                return Some(expected_root_location);
+            }
+        }
+    }
+
+    None
+}
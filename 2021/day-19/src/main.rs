@@ -0,0 +1,4 @@
+use aoc2021_day_19::Day19;
+use aoc_lib::*;
+
+aoc_setup!(Day19, sample 1: 79, sample 2: 3621, part 1: 398, part 2: 10965);
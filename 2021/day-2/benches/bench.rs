@@ -0,0 +1,3 @@
+use aoc2021_day_2::Day2;
+
+aoc_lib::aoc_bench!(Day2);
@@ -1,7 +1,5 @@
 use aoc_lib::*;
 
-aoc_setup!(Day2, sample 1: 150, sample 2: 900, part 1: 1654760, part 2: 1956047400);
-
 #[derive(Clone, Copy)]
 pub enum Direction {
     Forward,
@@ -13,7 +11,8 @@ pub struct Day2;
 
 impl AdventOfCode for Day2 {
     type Input = Vec<(Direction, isize)>;
-    type Output = isize;
+    type Output1 = isize;
+    type Output2 = isize;
 
     fn parse_input(s: &str) -> Self::Input {
         s.lines()
@@ -32,7 +31,7 @@ impl AdventOfCode for Day2 {
             .collect()
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
         let sub = input.iter().fold((0, 0), |sub, &(dir, dist)| match dir {
             Direction::Forward => (sub.0 + dist, sub.1),
             Direction::Down => (sub.0, sub.1 + dist),
@@ -41,7 +40,7 @@ impl AdventOfCode for Day2 {
         sub.0 * sub.1
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
         let sub = input.iter().fold((0, 0, 0), |sub, &(dir, dist)| match dir {
             Direction::Forward => (sub.0 + dist, sub.1 + sub.2 * dist, sub.2),
             Direction::Down => (sub.0, sub.1, sub.2 + dist),
@@ -0,0 +1,4 @@
+use aoc2021_day_2::Day2;
+use aoc_lib::*;
+
+aoc_setup!(Day2, sample 1: 150, sample 2: 900, part 1: 1654760, part 2: 1956047400);
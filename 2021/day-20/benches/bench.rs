@@ -0,0 +1,3 @@
+use aoc2021_day_20::Day20;
+
+aoc_lib::aoc_bench!(Day20);
@@ -0,0 +1,114 @@
+use aoc_lib::{
+    utils::{Field2D, FromChar, InfiniteGrid, WINDOW_3X3},
+    *,
+};
+
+pub struct Day20;
+
+impl TryAdventOfCode for Day20 {
+    type Input = (Vec<bool>, Field2D<bool>);
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = ParseError;
+
+    fn try_parse_input(s: &str) -> Result<Self::Input, Self::Error> {
+        let (algorithm, image) = split_at_blank_line(s)
+            .ok_or_else(|| ParseError::new(&s[s.len()..], "a blank line after the algorithm"))?;
+        let v = algorithm
+            .lines()
+            .flat_map(|line| line.char_indices().map(move |(i, c)| (&line[i..], c)))
+            .map(|(found, c)| {
+                bool::from_char(c)
+                    .ok_or_else(|| ParseError::new(&found[..c.len_utf8()], bool::EXPECTED))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.located(s))?;
+        if v.len() != 512 {
+            return Err(
+                ParseError::new(algorithm.trim_end(), "an algorithm of 512 cells").located(s),
+            );
+        }
+        let field = Field2D::parse(image).map_err(|e| e.located(s))?;
+
+        Ok((v, field))
+    }
+
+    fn try_solve_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(do_stuff(input, 2))
+    }
+
+    fn try_solve_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(do_stuff(input, 50))
+    }
+}
+
+/// Splits the input around the first empty line, which may end in `\r\n`.
+fn split_at_blank_line(s: &str) -> Option<(&str, &str)> {
+    let mut start = 0;
+    for line in s.split_inclusive('\n') {
+        if line.trim_end().is_empty() {
+            return Some((&s[..start], &s[start + line.len()..]));
+        }
+        start += line.len();
+    }
+    None
+}
+
+fn do_stuff(input: &(Vec<bool>, Field2D<bool>), count: usize) -> usize {
+    let algorithm = &input.0;
+    debug_assert_eq!(algorithm.len(), 512);
+
+    let mut image = InfiniteGrid::from_field(input.1.clone(), false);
+    for _ in 0..count {
+        image = enhance(algorithm, &image);
+    }
+
+    debug_assert!(!image.background(), "infinitely many pixels are lit");
+    image.iter().filter(|(_, &lit)| lit).count()
+}
+
+/// Applies the algorithm once. Only the pixels next to the lit area can change, everything further
+/// out follows what the algorithm does with the background.
+fn enhance(algorithm: &[bool], image: &InfiniteGrid<bool>) -> InfiniteGrid<bool> {
+    let background = algorithm[if *image.background() { 511 } else { 0 }];
+    let (xs, ys) = (image.x_range(), image.y_range());
+    let (min, max) = (
+        (xs.start() - 1, ys.start() - 1),
+        (xs.end() + 1, ys.end() + 1),
+    );
+
+    let mut next = InfiniteGrid::new(background);
+    next.reserve(min, max);
+    for y in min.1..=max.1 {
+        for x in min.0..=max.0 {
+            next[(x, y)] = algorithm[read_square_at(image, (x, y))];
+        }
+    }
+    next
+}
+
+fn read_square_at(image: &InfiniteGrid<bool>, (x, y): (isize, isize)) -> usize {
+    WINDOW_3X3.into_iter().fold(0, |acc, (dx, dy)| {
+        acc << 1 | image[(x + dx, y + dy)] as usize
+    })
+}
+
+#[test]
+pub fn test_read_index() {
+    const INPUT: &str = "#..#.\n#....\n##..#\n..#..\n..###";
+    let image = InfiniteGrid::from_field(Field2D::parse(INPUT).unwrap(), false);
+    assert_eq!(read_square_at(&image, (2, 2)), 0b000100010);
+    assert_eq!(read_square_at(&image, (-1, -1)), 0b000000001);
+}
+
+#[test]
+pub fn test_parse_crlf() {
+    let input = include_str!("../sample.txt").replace('\n', "\r\n");
+    let (algorithm, image) = Day20::parse(&input).unwrap();
+    assert_eq!(algorithm.len(), 512);
+    assert_eq!((image.width(), image.height()), (5, 5));
+
+    let error = Day20::parse("#.#\n\n#.").unwrap_err();
+    assert_eq!(error.found, "#.#");
+    assert_eq!(error.position.map(|p| (p.line, p.column)), Some((1, 1)));
+}
@@ -0,0 +1,4 @@
+use aoc2021_day_20::Day20;
+use aoc_lib::*;
+
+aoc_setup!(Day20, sample 1: 35, sample 2: 3351, part 1: 5489, part 2: 19066);
@@ -0,0 +1,3 @@
+use aoc2021_day_21::Day21;
+
+aoc_lib::aoc_bench!(Day21);
@@ -1,12 +1,11 @@
 use aoc_lib::*;
 
-aoc_setup!(Day21, sample 1: 739785, sample 2: 444356092776315, part 1: 675024, part 2: 570239341223618);
-
 pub struct Day21;
 
 impl AdventOfCode for Day21 {
     type Input = (usize, usize);
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(s: &str) -> Self::Input {
         // input always has same length :3c
@@ -15,7 +14,7 @@ impl AdventOfCode for Day21 {
     }
 
     // NOTE: I could precalculate a table for this since there are only 100 options
-    fn solve_1(input: &Self::Input) -> Self::Output {
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
         let (mut die, mut die_roll_count) = (0, 0);
         let (mut score1, mut score2) = (0, 0);
         let (mut pos1, mut pos2) = input;
@@ -53,7 +52,7 @@ impl AdventOfCode for Day21 {
         }
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
         #[derive(Clone, Copy)]
         struct State {
             score1: usize,
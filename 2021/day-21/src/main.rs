@@ -0,0 +1,4 @@
+use aoc2021_day_21::Day21;
+use aoc_lib::*;
+
+aoc_setup!(Day21, sample 1: 739785, sample 2: 444356092776315, part 1: 675024, part 2: 570239341223618);
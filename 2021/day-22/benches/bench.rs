@@ -0,0 +1,3 @@
+use aoc2021_day_22::Day22;
+
+aoc_lib::aoc_bench!(Day22);
@@ -0,0 +1,27 @@
+use aoc2021_day_22::{Day22, Day22Signed};
+use aoc_lib::SharedAdventOfCode;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Compares how long each engine takes to replay the reboot.
+fn bench_engines(c: &mut Criterion) {
+    let inputs = aoc_lib::day_input(env!("CARGO_MANIFEST_DIR"))
+        .expect("could not read input.txt")
+        .map(|input| ("input", input))
+        .into_iter()
+        .chain([("sample input", include_str!("../sample.txt").to_string())]);
+
+    let mut group = c.benchmark_group("reboot");
+    for (name, input) in inputs {
+        let parsed = Day22::parse(&input).unwrap();
+        group.bench_with_input(BenchmarkId::new("split", name), &parsed, |b, parsed| {
+            b.iter(|| Day22::prepare(black_box(parsed)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("signed", name), &parsed, |b, parsed| {
+            b.iter(|| Day22Signed::prepare(black_box(parsed)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_engines);
+criterion_main!(benches);
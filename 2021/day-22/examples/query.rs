@@ -0,0 +1,74 @@
+//! Reboots the reactor with the instructions on stdin, and answers questions about it: a cube like
+//! `10,10,10` tells whether it's on, a cuboid like `x=10..12,y=10..12,z=10..12` how many of its
+//! cubes are on.
+//!
+//! Usage: `cargo run -p aoc2021-day-22 --example query -- [--steps <n>] <cube or cuboid>... < input.txt`
+
+use aoc2021_day_22::{parse_cuboid, Day22, Reactor};
+use std::process::exit;
+
+use aoc_lib::{utils::BoxSet, *};
+
+fn main() {
+    let mut args = Args::from_env();
+    let steps = args.take_value("steps").map(|steps| {
+        steps.parse::<usize>().unwrap_or_else(|_| {
+            eprintln!("invalid --steps {:?}, expected a number", steps);
+            exit(1);
+        })
+    });
+    let input = read_stdin().unwrap_or_else(|e| {
+        eprintln!("could not read stdin: {}", e);
+        exit(1);
+    });
+    let instructions = Day22::parse(&input).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        exit(1);
+    });
+
+    let steps = steps.unwrap_or(instructions.len()).min(instructions.len());
+    let reactor = BoxSet::reboot(&instructions[..steps]);
+    println!(
+        "After {} of {} steps, {} cubes are on",
+        steps,
+        instructions.len(),
+        reactor.count()
+    );
+
+    let mut failed = false;
+    for query in args.positional() {
+        if query.contains('=') {
+            match parse_cuboid(query) {
+                Ok(cuboid) => println!("{}: {} on", query, reactor.count_within(&cuboid)),
+                Err(e) => {
+                    eprintln!("{}: {}", query, e);
+                    failed = true;
+                }
+            }
+        } else {
+            match parse_cube(query) {
+                Ok(cube) => {
+                    let state = if reactor.is_on(cube) { "on" } else { "off" };
+                    println!("{}: {}", query, state);
+                }
+                Err(e) => {
+                    eprintln!("{}: {}", query, e);
+                    failed = true;
+                }
+            }
+        }
+    }
+    if failed {
+        exit(1);
+    }
+}
+
+fn parse_cube(text: &str) -> Result<[i32; 3], ParseError> {
+    let coordinates = text
+        .split(',')
+        .map(parse_number)
+        .collect::<Result<Vec<_>, _>>()?;
+    coordinates
+        .try_into()
+        .map_err(|_| ParseError::new(text, "a cube in the form 'x,y,z'"))
+}
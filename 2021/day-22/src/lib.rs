@@ -0,0 +1,296 @@
+use aoc_lib::{
+    utils::{AaBox, BoxSet, Interval},
+    *,
+};
+use rustc_hash::FxHashMap;
+
+pub type Cuboid = AaBox<i32, 3>;
+
+/// The cubes that part 1 is limited to.
+const INIT_AREA: Cuboid = AaBox::new([Interval::new(-50, 50); 3]);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    state: bool,
+    region: Cuboid,
+}
+
+impl Instruction {
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let (state, region) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(line, "'on' or 'off' followed by a region"))?;
+
+        Ok(Self {
+            state: match state {
+                "on" => true,
+                "off" => false,
+                _ => return Err(ParseError::new(state, "'on' or 'off'")),
+            },
+            region: parse_cuboid(region)?,
+        })
+    }
+}
+
+pub fn parse_cuboid(text: &str) -> Result<Cuboid, ParseError> {
+    let mut parts = text.split(',');
+    let mut next_range = |prefix: &str, expected: &'static str| {
+        let part = parts
+            .next()
+            .ok_or_else(|| ParseError::new(&text[text.len()..], expected))?;
+        let range = part
+            .strip_prefix(prefix)
+            .ok_or_else(|| ParseError::new(part, expected))?;
+        parse_interval(range)
+    };
+
+    Ok(AaBox::new([
+        next_range("x=", "a range starting with 'x='")?,
+        next_range("y=", "a range starting with 'y='")?,
+        next_range("z=", "a range starting with 'z='")?,
+    ]))
+}
+
+fn parse_interval(text: &str) -> Result<Interval<i32>, ParseError> {
+    let (a, b) = text
+        .split_once("..")
+        .ok_or_else(|| ParseError::new(text, "a range in the form 'a..b'"))?;
+    Ok(Interval::between(parse_number(a)?, parse_number(b)?))
+}
+
+/// What the reactor looks like after a reboot, which knows which cubes are on.
+pub trait Reactor {
+    /// Follows the instructions in order. Replaying only the first few shows what the reactor
+    /// looked like part way through the reboot.
+    fn reboot(instructions: &[Instruction]) -> Self;
+
+    fn is_on(&self, cube: [i32; 3]) -> bool;
+
+    fn count(&self) -> u64;
+
+    /// The number of cubes that are on within `area`.
+    fn count_within(&self, area: &Cuboid) -> u64;
+}
+
+/// Keeps the cubes that are on as cuboids that don't overlap. Switching cubes off splits every
+/// cuboid that it touches into the pieces around it.
+impl Reactor for BoxSet<i32, 3> {
+    fn reboot(instructions: &[Instruction]) -> Self {
+        let mut cubes = BoxSet::new();
+
+        for &instruction in instructions {
+            if instruction.state {
+                cubes.insert(instruction.region);
+            } else {
+                cubes.remove(instruction.region);
+            }
+        }
+
+        cubes
+    }
+
+    fn is_on(&self, cube: [i32; 3]) -> bool {
+        self.contains(cube)
+    }
+
+    fn count(&self) -> u64 {
+        self.volume()
+    }
+
+    fn count_within(&self, area: &Cuboid) -> u64 {
+        self.iter()
+            .filter_map(|c| c.intersection(area))
+            .map(|c| c.volume())
+            .sum()
+    }
+}
+
+/// Keeps every cuboid that was switched on without cutting anything up. Every overlap is added
+/// again with the opposite sign, so it cancels out what would otherwise be counted twice, or what
+/// was switched off.
+#[derive(Debug, Default, Clone)]
+pub struct SignedCuboids {
+    groups: Vec<SignedGroup>,
+}
+
+/// The cuboids that one instruction added, which all lie within its region. An instruction that
+/// doesn't touch the region can skip the whole group.
+#[derive(Debug, Clone)]
+struct SignedGroup {
+    bounds: Cuboid,
+    /// How many times each cuboid counts, which may be negative.
+    cuboids: Vec<(Cuboid, i64)>,
+}
+
+impl SignedCuboids {
+    fn iter(&self) -> impl Iterator<Item = &(Cuboid, i64)> {
+        self.groups.iter().flat_map(|group| &group.cuboids)
+    }
+}
+
+impl Reactor for SignedCuboids {
+    fn reboot(instructions: &[Instruction]) -> Self {
+        let mut groups = Vec::<SignedGroup>::new();
+        let mut changes = FxHashMap::default();
+
+        for instruction in instructions {
+            let region = instruction.region;
+            for group in groups
+                .iter()
+                .filter(|group| group.bounds.intersects(&region))
+            {
+                for (cuboid, sign) in &group.cuboids {
+                    if let Some(overlap) = cuboid.intersection(&region) {
+                        *changes.entry(overlap).or_insert(0) -= sign;
+                    }
+                }
+            }
+            if instruction.state && !region.is_empty() {
+                *changes.entry(region).or_insert(0) += 1;
+            }
+
+            // overlaps that cancel out don't need to be kept at all
+            let cuboids = changes
+                .drain()
+                .filter(|&(_, sign)| sign != 0)
+                .collect::<Vec<_>>();
+            if !cuboids.is_empty() {
+                groups.push(SignedGroup {
+                    bounds: region,
+                    cuboids,
+                });
+            }
+        }
+
+        Self { groups }
+    }
+
+    fn is_on(&self, cube: [i32; 3]) -> bool {
+        let count: i64 = self
+            .iter()
+            .filter(|(cuboid, _)| cuboid.contains(cube))
+            .map(|(_, sign)| sign)
+            .sum();
+        count > 0
+    }
+
+    fn count(&self) -> u64 {
+        let count: i64 = self
+            .iter()
+            .map(|(cuboid, sign)| cuboid.volume() as i64 * sign)
+            .sum();
+        count as u64
+    }
+
+    fn count_within(&self, area: &Cuboid) -> u64 {
+        let count: i64 = self
+            .iter()
+            .filter_map(|(cuboid, sign)| Some(cuboid.intersection(area)?.volume() as i64 * sign))
+            .sum();
+        count as u64
+    }
+}
+
+/// Both parts count the cubes that are on after the whole reboot, part 1 just ignores those outside
+/// of the initialization area.
+macro_rules! solution {
+    ($(#[$attr:meta])* $name:ident => $reactor:ty) => {
+        $(#[$attr])*
+        pub struct $name;
+
+        impl SharedAdventOfCode for $name {
+            type Input = Vec<Instruction>;
+            type Shared = $reactor;
+            type Output1 = usize;
+            type Output2 = usize;
+            type Error = ParseError;
+
+            fn parse(s: &str) -> Result<Self::Input, Self::Error> {
+                s.lines()
+                    .map(Instruction::parse)
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| e.located(s))
+            }
+
+            fn prepare(input: &Self::Input) -> Result<Self::Shared, Self::Error> {
+                Ok(<$reactor>::reboot(input))
+            }
+
+            fn part_1(_: &Self::Input, reactor: &Self::Shared) -> Result<Self::Output1, Self::Error> {
+                Ok(reactor.count_within(&INIT_AREA) as usize)
+            }
+
+            fn part_2(_: &Self::Input, reactor: &Self::Shared) -> Result<Self::Output2, Self::Error> {
+                Ok(reactor.count() as usize)
+            }
+        }
+    };
+}
+
+solution!(Day22 => BoxSet<i32, 3>);
+solution!(
+    /// Solves the day with [SignedCuboids] instead of splitting cuboids up, which is the faster
+    /// engine. Run it with `aoc run 22 --engine signed`.
+    Day22Signed => SignedCuboids
+);
+
+#[test]
+fn test_region_size() {
+    assert_eq!(9, parse_cuboid("x=0..2,y=3..1,z=1..1").unwrap().volume());
+    assert_eq!(
+        198,
+        parse_cuboid("x=10..20,y=-1..1,z=0..5").unwrap().volume()
+    );
+}
+
+#[test]
+fn parse_error_points_at_offending_range() {
+    const TEST: &str = "on x=10..12,y=10..12,z=10..12\noff x=9..11,y=9..b,z=9..11";
+    let error = Day22::parse(TEST).unwrap_err();
+    assert_eq!(
+        Some(Position {
+            line: 2,
+            column: 18
+        }),
+        error.position
+    );
+    assert_eq!("b", error.found);
+}
+
+#[test]
+fn test_engines_agree() {
+    let inputs = [include_str!("../sample.txt").to_string()]
+        .into_iter()
+        .chain(day_input(env!("CARGO_MANIFEST_DIR")).unwrap());
+    for input in inputs {
+        assert_eq!(
+            solve_part_1::<Day22>(&input).unwrap(),
+            solve_part_1::<Day22Signed>(&input).unwrap()
+        );
+        assert_eq!(
+            solve_part_2::<Day22>(&input).unwrap(),
+            solve_part_2::<Day22Signed>(&input).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_queries() {
+    const TEST: &str = "on x=10..12,y=10..12,z=10..12\non x=11..13,y=11..13,z=11..13\noff x=9..11,y=9..11,z=9..11\non x=10..10,y=10..10,z=10..10";
+    let instructions = Day22::parse(TEST).unwrap();
+    let area = parse_cuboid("x=11..12,y=11..12,z=11..12").unwrap();
+
+    fn check<R: Reactor>(instructions: &[Instruction], area: &Cuboid) {
+        let halfway = R::reboot(&instructions[..2]);
+        assert!(halfway.is_on([11, 11, 11]) && halfway.is_on([13, 13, 13]));
+        assert!(!halfway.is_on([9, 9, 9]));
+        assert_eq!(8, halfway.count_within(area));
+
+        let done = R::reboot(instructions);
+        assert!(!done.is_on([11, 11, 11]) && done.is_on([10, 10, 10]));
+        assert_eq!(7, done.count_within(area));
+    }
+
+    check::<BoxSet<i32, 3>>(&instructions, &area);
+    check::<SignedCuboids>(&instructions, &area);
+}
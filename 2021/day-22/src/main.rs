@@ -0,0 +1,11 @@
+use aoc2021_day_22::Day22;
+use aoc_lib::*;
+
+aoc_setup!(
+    Day22,
+    sample 1: 474140,
+    sample 2: 2758514936282235,
+    part 1: 568000,
+    part 2: 1177411289280259,
+    inline "on x=10..12,y=10..12,z=10..12\non x=11..13,y=11..13,z=11..13\noff x=9..11,y=9..11,z=9..11\non x=10..10,y=10..10,z=10..10" 1: 39
+);
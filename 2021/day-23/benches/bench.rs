@@ -0,0 +1,3 @@
+use aoc2021_day_23::Day23;
+
+aoc_lib::aoc_bench!(Day23);
@@ -0,0 +1,32 @@
+//! Prints every move of the cheapest way to organize the amphipods in the burrow on stdin.
+//!
+//! Usage: `cargo run -p aoc2021-day-23 --example path [-- --part 2] < input.txt`
+
+use std::process::exit;
+
+use aoc2021_day_23::Day23;
+use aoc_lib::*;
+
+fn main() {
+    let part = Args::from_env().take_value("part");
+    let input = read_stdin().unwrap_or_else(|e| {
+        eprintln!("could not read stdin: {}", e);
+        exit(1);
+    });
+    let burrow = Day23::parse_input(&input);
+
+    match part.as_deref() {
+        None | Some("1") => {
+            let steps = Day23::optimal_steps_1(&burrow);
+            print!("{}", Day23::render_steps(&burrow, &steps));
+        }
+        Some("2") => {
+            let steps = Day23::optimal_steps_2(&burrow);
+            print!("{}", Day23::render_steps(&burrow.extend(), &steps));
+        }
+        Some(part) => {
+            eprintln!("Unknown part: {}", part);
+            exit(1);
+        }
+    }
+}
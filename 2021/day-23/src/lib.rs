@@ -1,16 +1,12 @@
 use std::{
     cmp::Ordering,
-    collections::BinaryHeap,
     fmt::Display,
     ops::{Index, IndexMut},
 };
 
 use aoc_lib::*;
-use rustc_hash::FxHashMap;
 use tinyvec::ArrayVec;
 
-aoc_setup!(Day23, sample 1: 12521, sample 2: 44169, part 1: 11417, part 2: 49529);
-
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Burrow<const N: usize> {
     side_rooms: [[Option<Amphipod>; N]; 4],
@@ -116,6 +112,32 @@ impl<const N: usize> Burrow<N> {
         self[the_move.to] = None;
     }
 
+    /// A lower bound on the energy needed to solve the burrow: every amphipod that isn't in its
+    /// own room has to at least walk to it and step inside.
+    pub fn min_remaining_energy(&self) -> usize {
+        let hallway = self.hallway.iter().enumerate().map(|(i, &a)| (a, i, 0));
+        let side_rooms = self
+            .side_rooms
+            .iter()
+            .enumerate()
+            .flat_map(|(room, slots)| {
+                slots
+                    .iter()
+                    .enumerate()
+                    .filter(move |&(_, &a)| a.map(Amphipod::get_room_index) != Some(room))
+                    .map(move |(depth, &a)| (a, 2 + room * 2, depth + 1))
+            });
+
+        hallway
+            .chain(side_rooms)
+            .filter_map(|(amphipod, x, y)| {
+                let amphipod = amphipod?;
+                let target_x = 2 + amphipod.get_room_index() * 2;
+                Some(amphipod.weight() * (y + x.abs_diff(target_x) + 1))
+            })
+            .sum()
+    }
+
     pub fn is_solved(&self) -> bool {
         self.side_rooms[0]
             .iter()
@@ -224,8 +246,8 @@ impl<const N: usize> IndexMut<Location> for Burrow<N> {
 
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Move {
-    from: Location,
-    to: Location,
+    pub from: Location,
+    pub to: Location,
 }
 
 impl Move {
@@ -315,6 +337,15 @@ impl Location {
     }
 }
 
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Location::Hallway(idx) => write!(f, "hallway spot {}", idx),
+            Location::Sideroom(hall, idx) => write!(f, "room {} spot {}", hall, idx),
+        }
+    }
+}
+
 impl Default for Location {
     fn default() -> Self {
         Location::Hallway(usize::MAX)
@@ -374,96 +405,117 @@ pub struct Day23;
 
 impl AdventOfCode for Day23 {
     type Input = Burrow<2>;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(s: &str) -> Self::Input {
         Burrow::parse(s)
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
-        dijkstra(input)
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
+        lowest_energy(input)
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
-        dijkstra(&input.extend())
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
+        lowest_energy(&input.extend())
     }
 }
 
-#[derive(PartialEq, Eq)]
-struct State<const N: usize> {
-    burrow: Burrow<N>,
-    cost: usize,
-}
-
-impl<const N: usize> PartialOrd for State<N> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.cost.partial_cmp(&self.cost)
+impl Day23 {
+    /// The moves that organize the amphipods in part 1 using the least energy.
+    pub fn optimal_steps_1(input: &Burrow<2>) -> Vec<Step<2>> {
+        optimal_steps(input)
     }
-}
 
-impl<const N: usize> Ord for State<N> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
+    /// The moves that organize the amphipods in part 2 using the least energy.
+    pub fn optimal_steps_2(input: &Burrow<2>) -> Vec<Step<4>> {
+        optimal_steps(&input.extend())
     }
-}
 
-// TODO: could try a*
-fn dijkstra<const N: usize>(input: &Burrow<N>) -> usize {
-    // let mut dist = vec![(usize::MAX, None); input.data.len()];
-    let mut dist: FxHashMap<Burrow<N>, (usize, Option<Move>)> = FxHashMap::default();
-    let mut heap = BinaryHeap::new();
-
-    dist.insert(input.clone(), (0, None));
-
-    heap.push(State {
-        burrow: input.clone(),
-        cost: 0,
-    });
-
-    while let Some(State { burrow, cost }) = heap.pop() {
-        // exit out if we're done
-        if burrow.is_solved() {
-            let mut editable_burrow = burrow.clone();
-            let mut path = Vec::new();
-            let mut last_move = dist[&burrow].1;
-            path.push(last_move.unwrap());
-            // println!("{}", editable_burrow);
-            while let Some(prev) = last_move {
-                path.push(prev);
-                editable_burrow.undo_move(prev);
-                // println!("{}", editable_burrow);
-                last_move = dist[&editable_burrow].1;
-            }
-            path.reverse();
-            // dbg!(path);
-            return cost;
+    /// Draws the burrow before and after every step.
+    pub fn render_steps<const N: usize>(start: &Burrow<N>, steps: &[Step<N>]) -> String {
+        let mut rendered = start.to_string();
+        let mut total = 0;
+        for (i, step) in steps.iter().enumerate() {
+            total += step.energy;
+            rendered += &format!(
+                "\nStep {}: {} moves from {} to {} ({} energy, {} total)\n{}",
+                i + 1,
+                Amphipod::to_char(Some(step.amphipod)),
+                step.the_move.from,
+                step.the_move.to,
+                step.energy,
+                total,
+                step.burrow
+            );
         }
+        rendered
+    }
+}
 
-        // if we've been here before in a faster way, skip
-        if !dist.contains_key(&burrow) || cost > dist[&burrow].0 {
-            // println!("skipping {:?}", burrow);
-            continue;
-        }
+/// A single move on the way to organizing the amphipods.
+#[derive(Debug, Clone)]
+pub struct Step<const N: usize> {
+    pub the_move: Move,
+    pub amphipod: Amphipod,
+    pub energy: usize,
+    /// The burrow after the move.
+    pub burrow: Burrow<N>,
+}
 
-        // look at every candidate move
-        for new_move in burrow.generate_moves() {
-            // calculate next state
-            let next = State {
-                burrow: burrow.after_move(new_move),
-                cost: cost + new_move.cost(&burrow),
-            };
+fn cheapest_path<const N: usize>(input: &Burrow<N>) -> search::Path<Burrow<N>> {
+    let successors = |burrow: &Burrow<N>| {
+        let burrow = burrow.clone();
+        burrow
+            .generate_moves()
+            .into_iter()
+            .map(move |new_move| (burrow.after_move(new_move), new_move.cost(&burrow)))
+    };
+
+    search::astar(
+        input.clone(),
+        successors,
+        Burrow::min_remaining_energy,
+        Burrow::is_solved,
+    )
+    .expect("failed to find a path")
+}
 
-            // println!("new path with cost {}", next.cost);
+fn lowest_energy<const N: usize>(input: &Burrow<N>) -> usize {
+    cheapest_path(input).cost
+}
 
-            // if this is a cheaper way than the previous way to get to this state, store it
-            if !dist.contains_key(&next.burrow) || next.cost < dist[&next.burrow].0 {
-                dist.insert(next.burrow.clone(), (next.cost, Some(new_move)));
-                heap.push(next);
+fn optimal_steps<const N: usize>(input: &Burrow<N>) -> Vec<Step<N>> {
+    cheapest_path(input)
+        .states
+        .windows(2)
+        .map(|pair| {
+            let (before, after) = (&pair[0], &pair[1]);
+            let the_move = before
+                .generate_moves()
+                .into_iter()
+                .find(|&m| before.after_move(m) == *after)
+                .expect("consecutive states should be a single move apart");
+
+            Step {
+                the_move,
+                amphipod: before[the_move.from].unwrap(),
+                energy: the_move.cost(before),
+                burrow: after.clone(),
             }
-        }
-    }
-
-    // println!("{:?}", dist);
+        })
+        .collect()
+}
 
-    panic!("failed to find a path")
+#[test]
+fn test_optimal_steps() {
+    let input = Day23::parse_input(include_str!("../sample.txt"));
+    let steps = Day23::optimal_steps_1(&input);
+    assert_eq!(12521, steps.iter().map(|s| s.energy).sum::<usize>());
+    assert!(steps.last().unwrap().burrow.is_solved());
+
+    let rendered = Day23::render_steps(&input, &steps);
+    assert!(rendered.ends_with(
+        "12521 total)\n#############\n#...........#\n###A#B#C#D###\n  #A#B#C#D#\n  #########\n"
+    ));
 }
@@ -0,0 +1,4 @@
+use aoc2021_day_23::Day23;
+use aoc_lib::*;
+
+aoc_setup!(Day23, sample 1: 12521, sample 2: 44169, part 1: 11417, part 2: 49529);
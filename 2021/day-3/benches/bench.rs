@@ -0,0 +1,3 @@
+use aoc2021_day_3::Day3;
+
+aoc_lib::aoc_bench!(Day3);
@@ -1,12 +1,11 @@
 use aoc_lib::*;
 
-aoc_setup!(Day3, sample 1: 198, sample 2: 230, part 1: 4191876, part 2: 3414905);
-
 pub struct Day3;
 
 impl AdventOfCode for Day3 {
     type Input = (usize, Vec<usize>);
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(s: &str) -> Self::Input {
         (
@@ -17,7 +16,7 @@ impl AdventOfCode for Day3 {
         )
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
         let mask = (1 << input.0) - 1;
         let half_len = input.1.len() / 2;
 
@@ -30,7 +29,7 @@ impl AdventOfCode for Day3 {
         gamma * epsilon
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
         let part1 = solve_2_sub(input, false);
         let part2 = solve_2_sub(input, true);
 
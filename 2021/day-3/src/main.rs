@@ -0,0 +1,4 @@
+use aoc2021_day_3::Day3;
+use aoc_lib::*;
+
+aoc_setup!(Day3, sample 1: 198, sample 2: 230, part 1: 4191876, part 2: 3414905);
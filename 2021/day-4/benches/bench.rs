@@ -0,0 +1,3 @@
+use aoc2021_day_4::Day4;
+
+aoc_lib::aoc_bench!(Day4);
@@ -2,8 +2,6 @@ use aoc_lib::*;
 use itertools::Itertools;
 use std::ops::IndexMut;
 
-aoc_setup!(Day4, sample 1: 4512, sample 2: 1924, part 1: 16674, part 2: 7075);
-
 #[derive(Debug)]
 pub struct Input(Vec<u8>, Vec<Board>);
 
@@ -49,7 +47,8 @@ pub struct Day4;
 
 impl AdventOfCode for Day4 {
     type Input = Input;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(s: &str) -> Self::Input {
         let mut iter = s.lines().filter(|line| !line.is_empty());
@@ -81,7 +80,7 @@ impl AdventOfCode for Day4 {
         Input(x, boards)
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
         let mut solve_mask = vec![0u32; input.1.len()];
         let mut last_num = 0;
 
@@ -104,7 +103,7 @@ impl AdventOfCode for Day4 {
         input.1[board_index].get_unmarked_sum(solve_mask[board_index]) * last_num
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
         let mut solve_mask = vec![0u32; input.1.len()];
         let mut last_num = 0;
         let mut solved_list = vec![0usize; (input.1.len() / (usize::BITS as usize)) + 1];
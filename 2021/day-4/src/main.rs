@@ -0,0 +1,4 @@
+use aoc2021_day_4::Day4;
+use aoc_lib::*;
+
+aoc_setup!(Day4, sample 1: 4512, sample 2: 1924, part 1: 16674, part 2: 7075);
@@ -0,0 +1,3 @@
+use aoc2021_day_5::Day5;
+
+aoc_lib::aoc_bench!(Day5);
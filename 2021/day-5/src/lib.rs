@@ -0,0 +1,107 @@
+use aoc_lib::{
+    utils::{InfiniteGrid, Point2},
+    *,
+};
+
+pub struct Grid<const MIN_VALUE: u8> {
+    grid: InfiniteGrid<u8>,
+    min_count: usize,
+}
+
+impl<const MIN_VALUE: u8> Grid<{ MIN_VALUE }> {
+    pub fn new() -> Self {
+        Self {
+            grid: InfiniteGrid::new(0),
+            min_count: 0,
+        }
+    }
+
+    /// Makes room for every point from `min` to `max`, so incrementing them never grows the grid.
+    pub fn reserve(&mut self, min: Point2<isize>, max: Point2<isize>) {
+        self.grid.reserve(min.into(), max.into());
+    }
+
+    pub fn increment(&mut self, point: Point2<isize>) {
+        let cell = &mut self.grid[point.into()];
+
+        *cell += 1;
+
+        if *cell == MIN_VALUE {
+            self.min_count += 1;
+        }
+    }
+}
+
+impl<const MIN_VALUE: u8> Default for Grid<{ MIN_VALUE }> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Day5;
+
+impl TryAdventOfCode for Day5 {
+    type Input = Vec<(Point2<isize>, Point2<isize>)>;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = ParseError;
+
+    fn try_parse_input(s: &str) -> Result<Self::Input, Self::Error> {
+        s.lines()
+            .map(|l| {
+                let (s1, s2) = l
+                    .split_once(" -> ")
+                    .ok_or_else(|| ParseError::new(l, "two points separated by ' -> '"))?;
+                Ok((parse_point(s1)?, parse_point(s2)?))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e: ParseError| e.located(s))
+    }
+
+    fn try_solve_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(count_overlaps(input, false))
+    }
+
+    fn try_solve_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(count_overlaps(input, true))
+    }
+}
+
+fn count_overlaps(input: &[(Point2<isize>, Point2<isize>)], with_diagonals: bool) -> usize {
+    let mut grid = Grid::<2>::new();
+    // lines never leave the box around their endpoints
+    let mut endpoints = input.iter().flat_map(|&(start, end)| [start, end]);
+    if let Some(first) = endpoints.next() {
+        let (min, max) = endpoints.fold((first, first), |(min, max), p| {
+            (
+                Point2::new(min.x.min(p.x), min.y.min(p.y)),
+                Point2::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        });
+        grid.reserve(min, max);
+    }
+
+    for &(start, end) in input {
+        let delta = end - start;
+        if delta.x != 0 && delta.y != 0 && (!with_diagonals || delta.x.abs() != delta.y.abs()) {
+            continue;
+        }
+
+        let step = Point2::new(delta.x.signum(), delta.y.signum());
+        let mut point = start;
+        grid.increment(point);
+        while point != end {
+            point += step;
+            grid.increment(point);
+        }
+    }
+
+    grid.min_count
+}
+
+fn parse_point(s: &str) -> Result<Point2<isize>, ParseError> {
+    let (x, y) = s
+        .split_once(',')
+        .ok_or_else(|| ParseError::new(s, "a point in the form 'x,y'"))?;
+    Ok(Point2::new(parse_number(x)?, parse_number(y)?))
+}
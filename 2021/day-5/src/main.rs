@@ -0,0 +1,4 @@
+use aoc2021_day_5::Day5;
+use aoc_lib::*;
+
+aoc_setup!(Day5, sample 1: 5, sample 2: 12, part 1: 4826, part 2: 16793);
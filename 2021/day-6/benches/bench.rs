@@ -0,0 +1,3 @@
+use aoc2021_day_6::Day6;
+
+aoc_lib::aoc_bench!(Day6);
@@ -1,28 +1,27 @@
 use aoc_lib::*;
 
-aoc_setup!(Day6, sample 1: 5934, sample 2: 26984457539, part 1: 360268);
-
 pub struct Day6;
 
 impl AdventOfCode for Day6 {
     type Input = Vec<usize>; // can be u8
-    type Output = u64;
+    type Output1 = u64;
+    type Output2 = u64;
 
     fn parse_input(s: &str) -> Self::Input {
         s.split(',').map(|l| l.parse().unwrap()).collect()
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
         solve_smart::<80>(input)
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
         solve_smart::<256>(input)
     }
 }
 
 pub fn solve_naive<const DAYS: usize>(input: &[usize]) -> u64 {
-    let mut vec: Vec<usize> = input.iter().copied().collect();
+    let mut vec: Vec<usize> = input.to_vec();
 
     for _day in 0..DAYS {
         let start_len = vec.len();
@@ -46,7 +45,7 @@ pub fn solve_smart<const DAYS: usize>(input: &[usize]) -> u64 {
     // for every starting fish, calculate the amount of offspring they generate
     let birthed_offspring: u64 = input
         .iter()
-        .map(|&fish| (map[8 - fish + DAYS])) // lower number means born earlier, means more fish
+        .map(|&fish| map[8 - fish + DAYS]) // lower number means born earlier, means more fish
         .sum();
 
     birthed_offspring // + (input.len() as u64)
@@ -56,7 +55,7 @@ fn generate_map(days: usize) -> Vec<u64> {
     // this map contains the number of offspring that are resultant from a fish born at time 0
     // TODO: use stack array instead of vector!
     // TODO: can build this map inside a const fn!!
-    let mut map = vec![0u64; (days + 8) as usize]; // + 7? + 0?
+    let mut map = vec![0u64; days + 8]; // + 7? + 0?
 
     for day in 0..map.len() {
         let added_fish = get_fish_count_from_map_day(&map, day);
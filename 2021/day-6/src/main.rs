@@ -0,0 +1,4 @@
+use aoc2021_day_6::Day6;
+use aoc_lib::*;
+
+aoc_setup!(Day6, sample 1: 5934, sample 2: 26984457539, part 1: 360268);
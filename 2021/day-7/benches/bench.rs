@@ -0,0 +1,3 @@
+use aoc2021_day_7::Day7;
+
+aoc_lib::aoc_bench!(Day7);
@@ -0,0 +1,4 @@
+use aoc2021_day_7::Day7;
+use aoc_lib::*;
+
+aoc_setup!(Day7, sample 1: 37, sample 2: 168, part 1: 355150, part 2: 98368490);
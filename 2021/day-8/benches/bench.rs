@@ -0,0 +1,3 @@
+use aoc2021_day_8::Day8;
+
+aoc_lib::aoc_bench!(Day8);
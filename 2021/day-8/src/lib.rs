@@ -1,7 +1,5 @@
 use aoc_lib::*;
 
-aoc_setup!(Day8, sample 1: 26, sample 2: 61229, part 1: 530, part 2: 1051087);
-
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub struct Digit(u8, u32);
 
@@ -47,7 +45,8 @@ pub struct Day8;
 
 impl AdventOfCode for Day8 {
     type Input = Vec<InputLine>;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(s: &str) -> Self::Input {
         s.lines()
@@ -69,16 +68,15 @@ impl AdventOfCode for Day8 {
             .collect()
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
         input
             .iter()
-            .map(|line| line.output)
-            .flatten()
+            .flat_map(|line| line.output)
             .filter(|digit| digit.get_number_from_segments().is_some())
             .count()
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
         input.iter().map(solve_2_single_line).sum()
     }
 }
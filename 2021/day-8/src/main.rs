@@ -0,0 +1,4 @@
+use aoc2021_day_8::Day8;
+use aoc_lib::*;
+
+aoc_setup!(Day8, sample 1: 26, sample 2: 61229, part 1: 530, part 2: 1051087);
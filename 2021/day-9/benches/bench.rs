@@ -0,0 +1,3 @@
+use aoc2021_day_9::Day9;
+
+aoc_lib::aoc_bench!(Day9);
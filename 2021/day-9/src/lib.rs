@@ -1,27 +1,27 @@
 use aoc_lib::utils::*;
 use aoc_lib::*;
 
-aoc_setup!(Day9, sample 1: 15, sample 2: 1134, part 1: 458, part 2: 1391940);
-
 pub struct Day9;
 
-impl AdventOfCode for Day9 {
+impl TryAdventOfCode for Day9 {
     type Input = Field2D<u8>;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = ParseError;
 
-    fn parse_input(s: &str) -> Self::Input {
-        Field2D::parse(s)
+    fn try_parse_input(s: &str) -> Result<Self::Input, Self::Error> {
+        Field2D::parse(s).map_err(|e| e.located(s))
     }
 
-    fn solve_1(input: &Self::Input) -> Self::Output {
-        (0..input.data.len())
+    fn try_solve_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        let risk = (0..input.data.len())
             .filter_map(|idx| {
                 let center = input.data[idx];
                 let higher_neighbours = input
                     .neighbour_indices(idx)
                     .iter()
                     .filter(|&&n| match n {
-                        Some(n) => input.data[n as usize] > center,
+                        Some(n) => input.data[n] > center,
                         None => true,
                     })
                     .count();
@@ -31,17 +31,19 @@ impl AdventOfCode for Day9 {
                     None
                 }
             })
-            .sum::<usize>()
+            .sum::<usize>();
+
+        Ok(risk)
     }
 
-    fn solve_2(input: &Self::Input) -> Self::Output {
+    fn try_solve_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
         let mut basin_field = Field2D::<usize> {
             data: vec![0; input.data.len()],
             stride: input.stride,
         };
         let (a, b, c) = flood_fill(input, &mut basin_field);
 
-        a * b * c
+        Ok(a * b * c)
     }
 }
 
@@ -0,0 +1,4 @@
+use aoc2021_day_9::Day9;
+use aoc_lib::*;
+
+aoc_setup!(Day9, sample 1: 15, sample 2: 1134, part 1: 458, part 2: 1391940);
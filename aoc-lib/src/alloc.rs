@@ -0,0 +1,172 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Display,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// A global allocator that counts the allocations of every thread, so that the memory use of the
+/// stages of a solution can be reported. Only binaries can pick the global allocator:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: aoc_lib::CountingAllocator = aoc_lib::CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    allocations: usize,
+    bytes: usize,
+    // signed, as a thread may free memory that another thread allocated
+    current: isize,
+    peak: isize,
+}
+
+thread_local! {
+    // const, so accessing it never allocates
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            allocations: 0,
+            bytes: 0,
+            current: 0,
+            peak: 0,
+        })
+    };
+}
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+fn record(allocated: usize, freed: usize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+
+    // threads that are shutting down can't count anymore
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        if allocated > 0 {
+            c.allocations += 1;
+            c.bytes += allocated;
+        }
+        c.current += allocated as isize - freed as isize;
+        c.peak = c.peak.max(c.current);
+        counters.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size(), 0);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(layout.size(), 0);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record(0, layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size, layout.size());
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// What a piece of code allocated on its thread, as counted by [CountingAllocator].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: usize,
+    pub bytes: usize,
+    /// The most memory that was in use at once, on top of what was in use before.
+    pub peak: usize,
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.count,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..1024 => format!("{} B", bytes),
+        1024..0x10_0000 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 0x10_0000 as f64),
+    }
+}
+
+/// Runs some code and counts what it allocates on the current thread. The counts are `None` if
+/// [CountingAllocator] isn't the global allocator.
+pub fn count_allocations<T>(fun: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    let before = COUNTERS.with(|counters| {
+        let before = counters.get();
+        // measure the peak from here on
+        counters.set(Counters {
+            peak: before.current,
+            ..before
+        });
+        before
+    });
+
+    let ret = fun();
+
+    let after = COUNTERS.with(|counters| {
+        let after = counters.get();
+        // keep the outer peak intact, for measurements that are nested in each other
+        counters.set(Counters {
+            peak: after.peak.max(before.peak),
+            ..after
+        });
+        after
+    });
+
+    let allocations = INSTALLED.load(Ordering::Relaxed).then(|| Allocations {
+        count: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak: (after.peak - before.current).max(0) as usize,
+    });
+    (ret, allocations)
+}
+
+#[test]
+fn test_format_allocations() {
+    let allocations = Allocations {
+        count: 3,
+        bytes: 1536,
+        peak: 512,
+    };
+    assert_eq!("3 allocs, 1.5 KiB, peak 512 B", allocations.to_string());
+    assert_eq!("2.0 MiB", format_bytes(2 << 20));
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_count_allocations() {
+    let (numbers, allocations) = count_allocations(|| {
+        drop(vec![0u8; 1000]);
+        vec![0u8; 100]
+    });
+    assert_eq!(100, numbers.len());
+    assert_eq!(
+        Some(Allocations {
+            count: 2,
+            bytes: 1100,
+            peak: 1000
+        }),
+        allocations
+    );
+}
@@ -0,0 +1,165 @@
+use std::{collections::BTreeMap, fmt::Display, io::ErrorKind, path::Path, str::FromStr};
+
+use crate::DayKey;
+
+/// What the server said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint.
+    Wrong,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "too high" => Ok(Self::TooHigh),
+            "too low" => Ok(Self::TooLow),
+            "wrong" => Ok(Self::Wrong),
+            _ => Err(format!("unknown verdict {:?}", s)),
+        }
+    }
+}
+
+/// Every answer that was submitted so far, along with its verdict.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnswerStore {
+    entries: BTreeMap<(DayKey, u8), Vec<(Verdict, String)>>,
+}
+
+impl AnswerStore {
+    const HEADER: &'static str = "year,day,part,verdict,answer";
+
+    /// Loads a store, or returns an empty one if the file doesn't exist yet.
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+
+        let mut store = Self::default();
+        for line in text.lines().skip(1).filter(|l| !l.is_empty()) {
+            // the answer comes last, so it may contain commas
+            let mut columns = line.splitn(5, ',');
+            let year = columns.next().and_then(|s| s.parse().ok());
+            let day = columns.next().and_then(|s| s.parse().ok());
+            let part = columns.next().and_then(|s| s.parse().ok());
+            let verdict = columns.next().and_then(|s| s.parse().ok());
+            match (year, day, part, verdict, columns.next()) {
+                (Some(year), Some(day), Some(part), Some(verdict), Some(answer)) => {
+                    store.record(DayKey::new(year, day), part, answer, verdict)
+                }
+                _ => {
+                    return Err(std::io::Error::new(
+                        ErrorKind::InvalidData,
+                        format!("invalid answer entry: {:?}", line),
+                    ))
+                }
+            }
+        }
+
+        Ok(store)
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let mut text = format!("{}\n", Self::HEADER);
+        for (&(key, part), answers) in &self.entries {
+            for (verdict, answer) in answers {
+                text += &format!("{},{},{},{},{}\n", key.year, key.day, part, verdict, answer);
+            }
+        }
+        std::fs::write(path, text)
+    }
+
+    /// Remembers the verdict for an answer, replacing an earlier one for the same answer.
+    pub fn record(&mut self, key: DayKey, part: u8, answer: &str, verdict: Verdict) {
+        let answers = self.entries.entry((key, part)).or_default();
+        answers.retain(|(_, a)| a != answer);
+        answers.push((verdict, answer.to_string()));
+    }
+
+    /// The verdict for an answer if it is already known. Besides answers that were submitted
+    /// before, this also knows that anything but a correct answer is wrong, and uses "too high"
+    /// and "too low" hints to rule out numbers past them.
+    pub fn verdict(&self, key: DayKey, part: u8, answer: &str) -> Option<Verdict> {
+        let answers = self.entries.get(&(key, part))?;
+        if answers.iter().any(|(v, _)| *v == Verdict::Correct) {
+            let correct = answers
+                .iter()
+                .any(|(v, a)| *v == Verdict::Correct && a == answer);
+            return Some(if correct {
+                Verdict::Correct
+            } else {
+                Verdict::Wrong
+            });
+        }
+
+        if let Some((verdict, _)) = answers.iter().find(|(_, a)| a == answer) {
+            return Some(*verdict);
+        }
+
+        let number = answer.parse::<i128>().ok()?;
+        let hinted = |verdict, past: fn(i128, i128) -> bool| {
+            answers
+                .iter()
+                .any(|(v, a)| *v == verdict && a.parse::<i128>().is_ok_and(|a| past(number, a)))
+        };
+        if hinted(Verdict::TooHigh, |n, a| n >= a) {
+            Some(Verdict::TooHigh)
+        } else if hinted(Verdict::TooLow, |n, a| n <= a) {
+            Some(Verdict::TooLow)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn test_verdict() {
+    let day = DayKey::new(2021, 1);
+    let mut store = AnswerStore::default();
+    store.record(day, 1, "500", Verdict::TooHigh);
+    store.record(day, 1, "100", Verdict::TooLow);
+    store.record(day, 1, "abc", Verdict::Wrong);
+
+    assert_eq!(Some(Verdict::TooHigh), store.verdict(day, 1, "600"));
+    assert_eq!(Some(Verdict::TooLow), store.verdict(day, 1, "100"));
+    assert_eq!(Some(Verdict::Wrong), store.verdict(day, 1, "abc"));
+    assert_eq!(None, store.verdict(day, 1, "300"));
+    assert_eq!(None, store.verdict(day, 2, "300"));
+
+    store.record(day, 1, "321", Verdict::Correct);
+    assert_eq!(Some(Verdict::Correct), store.verdict(day, 1, "321"));
+    assert_eq!(Some(Verdict::Wrong), store.verdict(day, 1, "300"));
+}
+
+#[test]
+fn test_save_and_load() {
+    let path = std::env::temp_dir().join(format!("aoc-answers-{}.csv", std::process::id()));
+    let mut store = AnswerStore::default();
+    store.record(DayKey::new(2021, 13), 1, "AB,CD", Verdict::Wrong);
+    store.record(DayKey::new(2022, 13), 2, "1759", Verdict::Correct);
+
+    store.save(&path).unwrap();
+    let loaded = AnswerStore::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(store, loaded);
+}
@@ -0,0 +1,66 @@
+/// Command line arguments, from which options can be taken out one at a time.
+#[derive(Debug, Clone, Default)]
+pub struct Args(Vec<String>);
+
+impl Args {
+    /// The arguments this process was started with, excluding the program name.
+    pub fn from_env() -> Self {
+        Self(std::env::args().skip(1).collect())
+    }
+
+    /// Removes a `--name value` or `--name=value` option and returns its value. A missing value
+    /// is returned as an empty string so it fails validation instead of being silently ignored.
+    pub fn take_value(&mut self, name: &str) -> Option<String> {
+        let flag = format!("--{}", name);
+        let prefix = format!("--{}=", name);
+
+        let i = self
+            .0
+            .iter()
+            .position(|a| *a == flag || a.starts_with(&prefix))?;
+        let arg = self.0.remove(i);
+        match arg.strip_prefix(&prefix) {
+            Some(value) => Some(value.to_string()),
+            None if i < self.0.len() => Some(self.0.remove(i)),
+            None => Some(String::new()),
+        }
+    }
+
+    /// Removes a `--name` switch and returns whether it was present.
+    pub fn take_flag(&mut self, name: &str) -> bool {
+        let flag = format!("--{}", name);
+        let len = self.0.len();
+        self.0.retain(|a| *a != flag);
+        self.0.len() != len
+    }
+
+    /// The arguments that haven't been taken as options.
+    pub fn positional(&self) -> Vec<&str> {
+        self.0.iter().map(String::as_str).collect()
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for Args {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+#[test]
+fn test_take_value() {
+    let mut args = ["run", "--format", "json", "16", "--other=x"]
+        .into_iter()
+        .collect::<Args>();
+    assert_eq!(Some("json".into()), args.take_value("format"));
+    assert_eq!(Some("x".into()), args.take_value("other"));
+    assert_eq!(None, args.take_value("format"));
+    assert_eq!(vec!["run", "16"], args.positional());
+}
+
+#[test]
+fn test_take_flag() {
+    let mut args = ["run", "--verbose", "all"].into_iter().collect::<Args>();
+    assert!(args.take_flag("verbose"));
+    assert!(!args.take_flag("verbose"));
+    assert_eq!(vec!["run", "all"], args.positional());
+}
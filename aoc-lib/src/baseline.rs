@@ -0,0 +1,321 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{args::Args, output::Record, DayKey, Report};
+
+/// Regressions smaller than this are ignored, as they are indistinguishable from noise.
+const NOISE_FLOOR: Duration = Duration::from_micros(1);
+
+/// Timings from an earlier run, stored in the same CSV layout as [OutputFormat::Csv].
+///
+/// [OutputFormat::Csv]: crate::OutputFormat::Csv
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    /// Parse, prepare and solve durations in nanoseconds, keyed by day and part.
+    entries: BTreeMap<(DayKey, u8), (u128, u128, u128)>,
+}
+
+impl Baseline {
+    /// Loads a baseline file, or returns `None` if it doesn't exist yet.
+    pub fn load(path: &Path) -> std::io::Result<Option<Self>> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let invalid = |line: &str| {
+            std::io::Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid baseline entry: {:?}", line),
+            )
+        };
+
+        let mut lines = text.lines();
+        // the columns after the answer, last one first. Finding the timings by name keeps files
+        // from before the memory columns were added working.
+        let right_columns = lines
+            .next()
+            .unwrap_or_default()
+            .rsplit(',')
+            .take_while(|&column| column != "answer")
+            .collect::<Vec<_>>();
+        let column = |name| right_columns.iter().position(|&column| column == name);
+
+        let mut entries = BTreeMap::new();
+        for line in lines.filter(|l| !l.is_empty()) {
+            // the answer may contain commas, so only split off the numeric columns
+            let mut left = line.splitn(4, ',');
+            let right = line
+                .rsplitn(right_columns.len() + 1, ',')
+                .take(right_columns.len())
+                .collect::<Vec<_>>();
+            let year = left.next().and_then(|s| s.parse::<u16>().ok());
+            let day = left.next().and_then(|s| s.parse::<u8>().ok());
+            let part = left.next().and_then(|s| s.parse::<u8>().ok());
+            let timing = |name| {
+                column(name)
+                    .and_then(|i| right.get(i))
+                    .and_then(|s| s.parse::<u128>().ok())
+            };
+            let (parse_ns, prepare_ns, solve_ns) =
+                (timing("parse_ns"), timing("prepare_ns"), timing("solve_ns"));
+
+            match (year, day, part, (parse_ns, prepare_ns, solve_ns)) {
+                (Some(year), Some(day), Some(part), (Some(parse), Some(prepare), Some(solve))) => {
+                    let key = DayKey::new(year, day);
+                    entries.insert((key, part), (parse, prepare, solve));
+                }
+                _ => return Err(invalid(line)),
+            }
+        }
+
+        Ok(Some(Self { entries }))
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let mut text = format!("{}\n", Record::CSV_HEADER);
+        for (&(key, part), &(parse_ns, prepare_ns, solve_ns)) in &self.entries {
+            let record = Record {
+                year: key.year,
+                day: key.day,
+                part,
+                input: None,
+                answer: "",
+                parse_ns,
+                prepare_ns,
+                solve_ns,
+                memory: None,
+            };
+            text += &record.to_csv();
+            text += "\n";
+        }
+        std::fs::write(path, text)
+    }
+
+    /// Stores the timings of a report, replacing any earlier ones for the same day.
+    pub fn update(&mut self, key: DayKey, report: &Report) {
+        for record in report.records(key) {
+            let timings = (record.parse_ns, record.prepare_ns, record.solve_ns);
+            self.entries.insert((key, record.part), timings);
+        }
+    }
+
+    /// Finds every stage of a report that got slower than `threshold` (e.g. 0.1 for 10%) compared
+    /// to this baseline. Stages without a time in the baseline, such as a prepare stage that didn't
+    /// exist when it was recorded, are skipped.
+    pub fn regressions(&self, key: DayKey, report: &Report, threshold: f64) -> Vec<Regression> {
+        let mut regressions = vec![];
+        let mut check = |stage, baseline: u128, current: Duration| {
+            let baseline = Duration::from_nanos(baseline as u64);
+            if baseline.is_zero() {
+                return;
+            }
+            if current > baseline.mul_f64(1.0 + threshold) && current - baseline > NOISE_FLOOR {
+                regressions.push(Regression {
+                    key,
+                    stage,
+                    baseline,
+                    current,
+                });
+            }
+        };
+
+        if let Some(&(parse_ns, prepare_ns, solve_ns)) = self.entries.get(&(key, 1)) {
+            check(Stage::Parse, parse_ns, report.parse_time);
+            if let Some(prepare_time) = report.prepare_time {
+                check(Stage::Prepare, prepare_ns, prepare_time);
+            }
+            check(Stage::Solve1, solve_ns, report.solve_1_time);
+        }
+        if let Some(&(_, _, solve_ns)) = self.entries.get(&(key, 2)) {
+            check(Stage::Solve2, solve_ns, report.solve_2_time);
+        }
+
+        regressions
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Prepare,
+    Solve1,
+    Solve2,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Stage::Parse => "parsing",
+            Stage::Prepare => "preparing",
+            Stage::Solve1 => "part 1",
+            Stage::Solve2 => "part 2",
+        })
+    }
+}
+
+/// A stage that got slower compared to the baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Regression {
+    pub key: DayKey,
+    pub stage: Stage,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let change = self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0;
+        write!(
+            f,
+            "{} {} regressed from {:?} to {:?} (+{:.0}%)",
+            self.key,
+            self.stage,
+            self.baseline,
+            self.current,
+            change * 100.0
+        )
+    }
+}
+
+/// The baseline options given on the command line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaselineConfig {
+    pub path: PathBuf,
+    /// How much slower a stage may get before it counts as a regression, e.g. 0.1 for 10%.
+    pub threshold: f64,
+    /// Whether to overwrite the baseline with the new timings, even if it already exists.
+    pub update: bool,
+}
+
+impl BaselineConfig {
+    pub const DEFAULT_THRESHOLD: f64 = 0.1;
+
+    /// Reads `--baseline <path>` (or the `AOC_BASELINE` environment variable),
+    /// `--threshold <percent>` and `--update-baseline`. Returns `None` if no baseline is used.
+    pub fn from_args(args: &mut Args) -> Result<Option<Self>, String> {
+        let path = args
+            .take_value("baseline")
+            .or_else(|| std::env::var("AOC_BASELINE").ok());
+        let threshold = args.take_value("threshold");
+        let update = args.take_flag("update-baseline");
+
+        let path = match path {
+            Some(path) if !path.is_empty() => PathBuf::from(path),
+            Some(_) => return Err("missing path for --baseline".into()),
+            None if threshold.is_some() || update => {
+                return Err("--threshold and --update-baseline require --baseline".into())
+            }
+            None => return Ok(None),
+        };
+
+        let threshold = match threshold {
+            Some(t) => match t.trim_end_matches('%').parse::<f64>() {
+                Ok(percent) if percent >= 0.0 => percent / 100.0,
+                _ => return Err(format!("invalid threshold {:?}, expected a percentage", t)),
+            },
+            None => Self::DEFAULT_THRESHOLD,
+        };
+
+        Ok(Some(Self {
+            path,
+            threshold,
+            update,
+        }))
+    }
+
+    /// Compares the reports against the baseline and returns all regressions. The baseline is
+    /// created if it doesn't exist yet, or updated with the new timings if requested.
+    pub fn check(&self, reports: &[(DayKey, &Report)]) -> std::io::Result<Vec<Regression>> {
+        let existing = Baseline::load(&self.path)?;
+
+        let regressions = match &existing {
+            Some(baseline) => reports
+                .iter()
+                .flat_map(|&(key, report)| baseline.regressions(key, report, self.threshold))
+                .collect(),
+            None => vec![],
+        };
+
+        if existing.is_none() || self.update {
+            let mut baseline = existing.unwrap_or_default();
+            for &(key, report) in reports {
+                baseline.update(key, report);
+            }
+            baseline.save(&self.path)?;
+        }
+
+        Ok(regressions)
+    }
+}
+
+#[cfg(test)]
+fn test_report(parse: u64, solve_1: u64, solve_2: u64) -> Report {
+    Report {
+        solution_1: "1,2".into(),
+        solution_2: "3".into(),
+        parse_time: Duration::from_micros(parse),
+        prepare_time: None,
+        solve_1_time: Duration::from_micros(solve_1),
+        solve_2_time: Duration::from_micros(solve_2),
+        stats: None,
+        memory: None,
+    }
+}
+
+#[test]
+fn test_regressions() {
+    let mut baseline = Baseline::default();
+    baseline.update(DayKey::new(2021, 3), &test_report(100, 100, 100));
+
+    let regressions = baseline.regressions(DayKey::new(2021, 3), &test_report(105, 150, 90), 0.1);
+    assert_eq!(1, regressions.len());
+    assert_eq!(Stage::Solve1, regressions[0].stage);
+    assert_eq!(
+        "2021 day 3 part 1 regressed from 100µs to 150µs (+50%)",
+        regressions[0].to_string()
+    );
+
+    assert!(baseline
+        .regressions(DayKey::new(2021, 4), &test_report(500, 500, 500), 0.1)
+        .is_empty());
+
+    // the baseline was recorded before the day had a prepare stage
+    let report = Report {
+        prepare_time: Some(Duration::from_micros(500)),
+        ..test_report(100, 100, 100)
+    };
+    assert!(baseline
+        .regressions(DayKey::new(2021, 3), &report, 0.1)
+        .is_empty());
+}
+
+#[test]
+fn test_save_and_load() {
+    let path = std::env::temp_dir().join(format!("aoc-baseline-{}.csv", std::process::id()));
+    let mut baseline = Baseline::default();
+    baseline.update(DayKey::new(2021, 3), &test_report(1, 2, 3));
+    baseline.update(DayKey::new(2022, 12), &test_report(4, 5, 6));
+
+    baseline.save(&path).unwrap();
+    let loaded = Baseline::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(Some(baseline), loaded);
+
+    // files from before the memory columns were added
+    let old = "year,day,part,answer,parse_ns,prepare_ns,solve_ns\n2021,3,1,,7,8,9\n";
+    std::fs::write(&path, old).unwrap();
+    let loaded = Baseline::load(&path).unwrap().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        Some(&(7, 8, 9)),
+        loaded.entries.get(&(DayKey::new(2021, 3), 1))
+    );
+}
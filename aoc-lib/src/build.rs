@@ -0,0 +1,95 @@
+//! Helpers for the build scripts of the day crates.
+
+use std::{fmt::Write, path::Path};
+
+use crate::NamedInput;
+
+/// Tells the compiler which inputs a day's crate has, for the tests generated by [aoc_setup]:
+///
+/// - `cfg(has_input)` is set if there is an `input.txt`
+/// - `$OUT_DIR/inputs.rs` holds a `solve_input_<name>` test for every input in the `inputs`
+///   directory, see [NamedInput]
+///
+/// The build script reruns when `input.txt` or the `inputs` directory changes, so adding or removing
+/// inputs is picked up. Every day has an `inputs` directory, as noticing one being created means
+/// watching the whole crate. Meant to be the only contents of a day's `build.rs`.
+///
+/// [aoc_setup]: crate::aoc_setup
+pub fn day() {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("not run by cargo");
+    let manifest_dir = Path::new(&manifest_dir);
+    // only the inputs, so editing a day's code doesn't run its build script again. Cargo treats
+    // a missing path as changed on every build, and a path being added only shows up in the
+    // directory around it, so that is watched instead until both exist.
+    let watched = [manifest_dir.join("input.txt"), manifest_dir.join("inputs")];
+    if watched.iter().all(|path| path.exists()) {
+        for path in &watched {
+            println!("cargo::rerun-if-changed={}", path.display());
+        }
+    } else {
+        println!("cargo::rerun-if-changed={}", manifest_dir.display());
+    }
+    println!("cargo::rustc-check-cfg=cfg(has_input)");
+    if manifest_dir.join("input.txt").exists() {
+        println!("cargo::rustc-cfg=has_input");
+    }
+
+    let out_dir = std::env::var("OUT_DIR").expect("not run by cargo");
+    let tests = input_tests(NamedInput::names(manifest_dir));
+    std::fs::write(Path::new(&out_dir).join("inputs.rs"), tests).expect("could not write tests");
+}
+
+/// One test per input, which calls the `solve_named_input` function that [aoc_setup] generates.
+/// If the directory can't be read, a single failing test says so instead.
+///
+/// [aoc_setup]: crate::aoc_setup
+fn input_tests(names: std::io::Result<Vec<String>>) -> String {
+    let names = match names {
+        Ok(names) => names,
+        Err(e) => {
+            return format!(
+                "#[test]\nfn solve_inputs() {{\n    panic!(\"could not read inputs: {{}}\", {:?});\n}}\n",
+                e.to_string()
+            )
+        }
+    };
+
+    let mut tests = String::new();
+    let mut idents = vec![];
+    for name in names {
+        let mut ident = name
+            .chars()
+            .map(|c| match c {
+                'a'..='z' | 'A'..='Z' | '0'..='9' => c.to_ascii_lowercase(),
+                _ => '_',
+            })
+            .collect::<String>();
+        // names that only differ in punctuation would end up with the same test
+        while idents.contains(&ident) {
+            ident.push('_');
+        }
+
+        writeln!(
+            tests,
+            "#[test]\nfn solve_input_{}() {{\n    solve_named_input({:?});\n}}",
+            ident, name
+        )
+        .unwrap();
+        idents.push(ident);
+    }
+    tests
+}
+
+#[test]
+fn test_input_tests() {
+    let names = ["alice", "Bob-2", "bob_2"].map(String::from).to_vec();
+    assert_eq!(
+        "#[test]\nfn solve_input_alice() {\n    solve_named_input(\"alice\");\n}\n\
+         #[test]\nfn solve_input_bob_2() {\n    solve_named_input(\"Bob-2\");\n}\n\
+         #[test]\nfn solve_input_bob_2_() {\n    solve_named_input(\"bob_2\");\n}\n",
+        input_tests(Ok(names))
+    );
+
+    let error = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
+    assert!(input_tests(Err(error)).contains("could not read inputs: {}\", \"denied\""));
+}
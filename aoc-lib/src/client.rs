@@ -0,0 +1,194 @@
+use std::{
+    io::{Error, ErrorKind},
+    path::Path,
+};
+
+use crate::{DayKey, Verdict};
+
+/// Downloads puzzle inputs from and submits answers to the Advent of Code website, or any server
+/// that mimics it.
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub const DEFAULT_BASE_URL: &'static str = "https://adventofcode.com";
+
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    /// Uses the session token in `AOC_SESSION`, and the server in `AOC_BASE_URL` if it is set.
+    /// Returns `None` if there is no session token.
+    pub fn from_env() -> Option<Self> {
+        let session = std::env::var("AOC_SESSION")
+            .ok()
+            .filter(|s| !s.is_empty())?;
+        let base_url =
+            std::env::var("AOC_BASE_URL").unwrap_or_else(|_| Self::DEFAULT_BASE_URL.into());
+        Some(Self::new(base_url, session))
+    }
+
+    pub fn fetch_input(&self, key: DayKey) -> std::io::Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, key.year, key.day);
+        self.request("GET", &url)
+            .call()
+            .map_err(|e| request_error("download input", key, e))?
+            .into_string()
+    }
+
+    /// Submits an answer and returns what the server thought of it.
+    pub fn submit(&self, key: DayKey, part: u8, answer: &str) -> std::io::Result<Verdict> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, key.year, key.day);
+        let page = self
+            .request("POST", &url)
+            .send_form(&[("level", part.to_string().as_str()), ("answer", answer)])
+            .map_err(|e| request_error("submit answer", key, e))?
+            .into_string()?;
+
+        if page.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if page.contains("That's not the right answer") {
+            Ok(if page.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if page.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            })
+        } else if page.contains("You gave an answer too recently") {
+            Err(Error::other(
+                "an answer was submitted too recently, try again later",
+            ))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Err(Error::other(
+                "this part is already solved, or not unlocked yet",
+            ))
+        } else {
+            Err(Error::new(
+                ErrorKind::InvalidData,
+                "could not find a verdict in the response",
+            ))
+        }
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        ureq::request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", "aoc-lib")
+    }
+
+    /// Reads the input stored at `path`, downloading and storing it there first if it doesn't
+    /// exist yet.
+    pub fn cached_input(&self, path: &Path, key: DayKey) -> std::io::Result<String> {
+        match std::fs::read_to_string(path) {
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                let input = self.fetch_input(key)?;
+                std::fs::write(path, &input)?;
+                Ok(input)
+            }
+            result => result,
+        }
+    }
+}
+
+fn request_error(action: &str, key: DayKey, error: ureq::Error) -> Error {
+    match error {
+        ureq::Error::Status(status, response) => Error::other(format!(
+            "could not {} for {}, server responded with {}: {}",
+            action,
+            key,
+            status,
+            response.into_string().unwrap_or_default().trim()
+        )),
+        ureq::Error::Transport(e) => Error::new(ErrorKind::ConnectionRefused, e),
+    }
+}
+
+/// Serves a single HTTP request with `body`, and returns the server URL along with a handle that
+/// resolves to the raw request.
+#[cfg(test)]
+fn serve_once(
+    status: &'static str,
+    body: &'static str,
+) -> (String, std::thread::JoinHandle<String>) {
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(&stream);
+        let mut request = String::new();
+        while reader.read_line(&mut request).unwrap() > 2 {}
+        let length = request
+            .lines()
+            .find_map(|l| l.strip_prefix("Content-Length: "))
+            .map_or(0, |l| l.parse().unwrap());
+        let mut content = vec![0; length];
+        reader.read_exact(&mut content).unwrap();
+        request += std::str::from_utf8(&content).unwrap();
+
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        (&stream).write_all(response.as_bytes()).unwrap();
+        request
+    });
+    (url, handle)
+}
+
+#[test]
+fn test_cached_input() {
+    let (url, server) = serve_once("200 OK", "1\n2\n3\n");
+    let client = Client::new(url, "secret");
+    let path = std::env::temp_dir().join(format!("aoc-input-{}.txt", std::process::id()));
+
+    assert_eq!(
+        "1\n2\n3\n",
+        client.cached_input(&path, DayKey::new(2021, 7)).unwrap()
+    );
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /2021/day/7/input HTTP/1.1\r\n"));
+    assert!(request.contains("\r\nCookie: session=secret\r\n"));
+
+    // the server is gone, so this has to come from the cache
+    assert_eq!(
+        "1\n2\n3\n",
+        client.cached_input(&path, DayKey::new(2021, 7)).unwrap()
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_fetch_error() {
+    let (url, server) = serve_once("400 Bad Request", "Please log in.");
+    let error = Client::new(url, "expired")
+        .fetch_input(DayKey::new(2021, 7))
+        .unwrap_err();
+    server.join().unwrap();
+    assert_eq!(
+        "could not download input for 2021 day 7, server responded with 400: Please log in.",
+        error.to_string()
+    );
+}
+
+#[test]
+fn test_submit() {
+    let page = "<article><p>That's not the right answer; your answer is too high.</p></article>";
+    let (url, server) = serve_once("200 OK", page);
+    let verdict = Client::new(url, "secret").submit(DayKey::new(2021, 7), 2, "1234");
+    let request = server.join().unwrap();
+
+    assert_eq!(Verdict::TooHigh, verdict.unwrap());
+    assert!(request.starts_with("POST /2021/day/7/answer HTTP/1.1\r\n"));
+    assert!(request.ends_with("\r\n\r\nlevel=2&answer=1234"));
+}
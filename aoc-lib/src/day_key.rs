@@ -0,0 +1,54 @@
+use std::fmt::Display;
+
+/// Identifies a puzzle by the year of the event and the day within it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayKey {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl DayKey {
+    pub const fn new(year: u16, day: u8) -> Self {
+        Self { year, day }
+    }
+
+    /// Extracts the year and day from a package name such as `aoc2021-day-16`. Anything that
+    /// can't be found is 0.
+    pub fn from_package_name(name: &str) -> Self {
+        let (year, day) = name.split_once("-day-").unwrap_or(("", name));
+        Self {
+            year: year.trim_start_matches("aoc").parse().unwrap_or(0),
+            day: day.parse().unwrap_or(0),
+        }
+    }
+
+    /// The name of the crate that solves this day.
+    pub fn package_name(self) -> String {
+        format!("aoc{}-day-{}", self.year, self.day)
+    }
+
+    /// The directory of the crate that solves this day, relative to the workspace root.
+    pub fn crate_dir(self) -> String {
+        format!("{}/day-{}", self.year, self.day)
+    }
+}
+
+impl Display for DayKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+#[test]
+fn test_from_package_name() {
+    let key = DayKey::from_package_name("aoc2021-day-16");
+    assert_eq!(DayKey::new(2021, 16), key);
+    assert_eq!("aoc2021-day-16", key.package_name());
+    assert_eq!("2021/day-16", key.crate_dir());
+    assert_eq!("2021 day 16", key.to_string());
+
+    assert_eq!(
+        DayKey::new(0, 0),
+        DayKey::from_package_name("something-else")
+    );
+}
@@ -0,0 +1,80 @@
+use std::{borrow::Cow, fmt::Display, str::FromStr};
+
+/// An error encountered while parsing puzzle input.
+///
+/// Errors are created from the fragment of input that could not be parsed, and get a line and
+/// column once they are [located](ParseError::located) in the full input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub expected: Cow<'static, str>,
+    pub found: String,
+    pub position: Option<Position>,
+    address: usize,
+}
+
+/// A 1-based line and column in the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl ParseError {
+    /// Creates an error for `found`, which should be a slice of the input being parsed.
+    pub fn new(found: &str, expected: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            expected: expected.into(),
+            found: found.to_string(),
+            position: None,
+            address: found.as_ptr() as usize,
+        }
+    }
+
+    /// Resolves the line and column of this error, if it was created from a slice of `input`.
+    #[must_use]
+    pub fn located(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.position.is_none() && (start..=start + input.len()).contains(&self.address) {
+            let before = &input[..self.address - start];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            self.position = Some(Position {
+                line: before.matches('\n').count() + 1,
+                column: before[line_start..].chars().count() + 1,
+            });
+        }
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(Position { line, column }) = self.position {
+            write!(f, "line {}, column {}: ", line, column)?;
+        }
+        write!(f, "expected {}, found {:?}", self.expected, self.found)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a number, or returns a [ParseError] pointing at the text that isn't one.
+pub fn parse_number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(s, "a number"))
+}
+
+#[test]
+fn test_located() {
+    let input = "1,2\n3,x\n";
+    let error = ParseError::new(&input[6..7], "a number").located(input);
+    assert_eq!(Some(Position { line: 2, column: 3 }), error.position);
+    assert_eq!(
+        "line 2, column 3: expected a number, found \"x\"",
+        error.to_string()
+    );
+}
+
+#[test]
+fn test_located_foreign_slice() {
+    let error = ParseError::new("x", "a number").located("1,2\n3,4");
+    assert_eq!(None, error.position);
+}
@@ -0,0 +1,142 @@
+use std::{io::ErrorKind, path::Path};
+
+use crate::Report;
+
+/// Someone's puzzle input from a day's `inputs` directory, along with the answers from its
+/// sidecar file if there is one.
+///
+/// An input `inputs/<name>.txt` has its answers in `inputs/<name>.answers`, with one
+/// `<part>: <answer>` line per part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedInput {
+    pub name: String,
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+impl NamedInput {
+    /// The names of the inputs in the `inputs` directory of a day's crate, ordered by name.
+    /// Returns an empty list if there is no such directory.
+    pub fn names(manifest_dir: impl AsRef<Path>) -> std::io::Result<Vec<String>> {
+        let dir = manifest_dir.as_ref().join("inputs");
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e),
+        };
+
+        let mut names = vec![];
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|e| e == "txt") {
+                names.push(path.file_stem().unwrap().to_string_lossy().into_owned());
+            }
+        }
+
+        names.sort();
+        Ok(names)
+    }
+
+    /// Loads a single input from the `inputs` directory of a day's crate, along with its answers.
+    pub fn load(manifest_dir: impl AsRef<Path>, name: &str) -> std::io::Result<Self> {
+        let path = manifest_dir.as_ref().join("inputs").join(name);
+
+        let answers = match std::fs::read_to_string(path.with_extension("answers")) {
+            Ok(text) => Self::parse_answers(&text).map_err(|line| {
+                std::io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid answer for input {:?}: {:?}", name, line),
+                )
+            })?,
+            Err(e) if e.kind() == ErrorKind::NotFound => [None, None],
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self {
+            input: std::fs::read_to_string(path.with_extension("txt"))?,
+            name: name.to_string(),
+            answers,
+        })
+    }
+
+    /// Parses `<part>: <answer>` lines, or returns the first line that isn't one.
+    fn parse_answers(text: &str) -> Result<[Option<String>; 2], &str> {
+        let mut answers = [None, None];
+        for line in text.lines().filter(|l| !l.trim().is_empty()) {
+            match line.split_once(':') {
+                Some(("1", answer)) => answers[0] = Some(answer.trim().to_string()),
+                Some(("2", answer)) => answers[1] = Some(answer.trim().to_string()),
+                _ => return Err(line),
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Compares a report for this input against the expected answers, describing every part
+    /// that doesn't match.
+    pub fn mismatches(&self, report: &Report) -> Vec<String> {
+        let solutions = [&report.solution_1, &report.solution_2];
+        (0..2)
+            .filter_map(|i| match &self.answers[i] {
+                Some(expected) if expected != solutions[i] => Some(format!(
+                    "{} part {}: expected {}, found {}",
+                    self.name,
+                    i + 1,
+                    expected,
+                    solutions[i]
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_load() {
+    let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("inputs")).unwrap();
+    std::fs::write(dir.join("inputs/bob.txt"), "2\n").unwrap();
+    std::fs::write(dir.join("inputs/alice.txt"), "1\n").unwrap();
+    std::fs::write(dir.join("inputs/alice.answers"), "1: 10\n2: AB,CD\n").unwrap();
+
+    std::fs::write(dir.join("inputs/carol.answers"), "3: 10\n").unwrap();
+    std::fs::write(dir.join("inputs/carol.txt"), "3\n").unwrap();
+
+    let names = NamedInput::names(&dir).unwrap();
+    let inputs = names
+        .iter()
+        .map(|name| NamedInput::load(&dir, name))
+        .collect::<Vec<_>>();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(["alice", "bob", "carol"], names.as_slice());
+    assert!(
+        inputs[2].is_err(),
+        "an invalid answers file only fails its own input"
+    );
+    let inputs = inputs
+        .into_iter()
+        .take(2)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!("alice", inputs[0].name);
+    assert_eq!([Some("10".into()), Some("AB,CD".into())], inputs[0].answers);
+    assert_eq!("bob", inputs[1].name);
+    assert_eq!([None, None], inputs[1].answers);
+
+    let report = Report {
+        solution_1: "10".into(),
+        solution_2: "AB".into(),
+        parse_time: Default::default(),
+        prepare_time: None,
+        solve_1_time: Default::default(),
+        solve_2_time: Default::default(),
+        stats: None,
+        memory: None,
+    };
+    assert_eq!(
+        vec!["alice part 2: expected AB,CD, found AB"],
+        inputs[0].mismatches(&report)
+    );
+    assert!(inputs[1].mismatches(&report).is_empty());
+}
@@ -1,80 +1,658 @@
+pub use alloc::{count_allocations, Allocations, CountingAllocator};
+pub use answers::{AnswerStore, Verdict};
+pub use args::Args;
+pub use baseline::{Baseline, BaselineConfig, Regression, Stage};
+#[cfg(feature = "client")]
+pub use client::Client;
+pub use day_key::DayKey;
+pub use error::{parse_number, ParseError, Position};
+pub use inputs::NamedInput;
+pub use output::{OutputFormat, Record};
+pub use parallel::{default_threads, parallel_map};
 pub use paste::paste;
-use std::fmt::Display;
+pub use stats::{time_repeated, Stats, StatsConfig};
+use std::{convert::Infallible, fmt::Display, time::Duration};
 
+mod alloc;
+mod answers;
+mod args;
+mod baseline;
+pub mod build;
+#[cfg(feature = "client")]
+mod client;
+mod day_key;
+mod error;
+mod inputs;
+mod output;
+mod parallel;
+pub mod search;
+mod stats;
 pub mod utils;
 
+/// Generates `main` and tests for a day. Tests are declared as `<kind> <part>: <answer>`, where
+/// the kind is one of:
+///
+/// - `sample`: solve `sample.txt`
+/// - `part`: solve `input.txt`, ignored if the crate has no `input.txt` (see [build::day])
+/// - `sample "<file>"`: solve another file in the crate root
+/// - `inline "<input>"`: solve the given input
+///
+/// The last two can be repeated as often as needed. Each of them is checked in its own test,
+/// `solve_example_<n>_part_<part>`, numbered in the order they are declared. Each one nests the
+/// expansion a level deeper, so a day with more than about 90 of them has to raise the compiler's
+/// limit with `#![recursion_limit = "256"]` in its `main.rs`. Every input in the crate's `inputs`
+/// directory is also checked against its answers, in a `solve_input_<name>` test that the build
+/// script generates, see [build::day] and [NamedInput].
+///
+/// With the `count-allocations` feature, [CountingAllocator] is installed as the global allocator,
+/// so `--memory` can report what every stage allocates.
 #[macro_export]
 macro_rules! aoc_setup {
-    ($type:ident $(, $test_type:ident $index:literal: $test_result:expr)*) => {
-        // TODO: pub use another macro that creates benchmarks?
+    ($type:ident $(, $($tests:tt)*)?) => {
+        $crate::global_allocator!();
 
         fn main() {
-            aoc_lib::run($type);
+            aoc_lib::run(aoc_lib::DayKey::from_package_name(env!("CARGO_PKG_NAME")), $type);
         }
 
-        $(
-            aoc_setup!(test_impl $test_type $type $index: $test_result);
-        )*
+        #[cfg(test)]
+        #[allow(dead_code)] // if the crate has no inputs
+        fn solve_named_input(name: &str) {
+            let input = aoc_lib::NamedInput::load(env!("CARGO_MANIFEST_DIR"), name).unwrap();
+            let mismatches = input.mismatches(&aoc_lib::solve($type, &input.input).unwrap());
+            assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/inputs.rs"));
+
+        // examples are numbered by a decimal counter, least significant digit first, where each
+        // digit carries the digits that come after it so counting up is a matter of matching
+        $crate::aoc_setup!(tests $type [(1 [2 3 4 5 6 7 8 9])] $($($tests)*)?);
+    };
+    (tests $type:ident [($digit:tt [$next:tt $($after:tt)*]) $($high:tt)*] $kind:ident $example:literal $index:literal: $test_result:expr $(, $($rest:tt)*)?) => {
+        $crate::aoc_setup!(example_impl $kind $type [($digit []) $($high)*] [] $index: $test_result, $example);
+        $crate::aoc_setup!(tests $type [($next [$($after)*]) $($high)*] $($($rest)*)?);
+    };
+    (tests $type:ident [($digit:tt []) $($high:tt)*] $kind:ident $example:literal $index:literal: $test_result:expr $(, $($rest:tt)*)?) => {
+        $crate::aoc_setup!(example_impl $kind $type [($digit []) $($high)*] [] $index: $test_result, $example);
+        $crate::aoc_setup!(carry $type [(0 [1 2 3 4 5 6 7 8 9])] [$($high)*] $($($rest)*)?);
+    };
+    (tests $type:ident $counter:tt $test_type:ident $index:literal: $test_result:expr $(, $($rest:tt)*)?) => {
+        $crate::aoc_setup!(test_impl $test_type $type $index: $test_result);
+        $crate::aoc_setup!(tests $type $counter $($($rest)*)?);
+    };
+    (tests $type:ident $counter:tt) => {};
+    (carry $type:ident [$($low:tt)*] [($digit:tt [$next:tt $($after:tt)*]) $($high:tt)*] $($rest:tt)*) => {
+        $crate::aoc_setup!(tests $type [$($low)* ($next [$($after)*]) $($high)*] $($rest)*);
+    };
+    (carry $type:ident [$($low:tt)*] [($digit:tt []) $($high:tt)*] $($rest:tt)*) => {
+        $crate::aoc_setup!(carry $type [$($low)* (0 [1 2 3 4 5 6 7 8 9])] [$($high)*] $($rest)*);
+    };
+    (carry $type:ident [$($low:tt)*] [] $($rest:tt)*) => {
+        $crate::aoc_setup!(tests $type [$($low)* (1 [2 3 4 5 6 7 8 9])] $($rest)*);
+    };
+    (example_impl $kind:ident $type:ident [($digit:tt $after:tt) $($high:tt)*] [$($n:tt)*] $index:literal: $test_result:expr, $example:literal) => {
+        $crate::aoc_setup!(example_impl $kind $type [$($high)*] [$digit $($n)*] $index: $test_result, $example);
+    };
+    (example_impl sample $type:ident [] $n:tt $index:literal: $test_result:expr, $file:literal) => {
+        $crate::aoc_setup!(example_test $type $n $index: $test_result, $file, include_str!(concat!("../", $file)));
+    };
+    (example_impl inline $type:ident [] $n:tt $index:literal: $test_result:expr, $input:literal) => {
+        $crate::aoc_setup!(example_test $type $n $index: $test_result, $input, $input);
+    };
+    (example_test $type:ident [$($n:tt)*] $index:literal: $test_result:expr, $name:expr, $input:expr) => {
+        aoc_lib::paste! {
+            #[test]
+            fn [<solve_example_ $($n)* _part_ $index>]() {
+                assert_eq!(
+                    $test_result,
+                    aoc_lib::[<solve_part_ $index>]::<$type>($input).unwrap(),
+                    "{:?}",
+                    $name,
+                );
+            }
+        }
     };
     (test_impl sample $type:ident $index:literal: $test_result:expr) => {
         aoc_lib::paste! {
             #[test]
             fn [<solve_sample_part_ $index>]() {
                 let input = include_str!("../sample.txt");
-                let parsed = $type::parse_input(input);
-                assert_eq!($test_result, $type::[< solve_ $index >](&parsed));
+                assert_eq!($test_result, aoc_lib::[<solve_part_ $index>]::<$type>(input).unwrap());
             }
         }
     };
     (test_impl part $type:ident $index:literal: $test_result:expr) => {
         aoc_lib::paste! {
             #[test]
+            #[cfg_attr(not(has_input), ignore = "no input.txt")]
             fn [<solve_part_ $index>]() {
-                let input = include_str!("../input.txt");
-                let parsed = $type::parse_input(input);
-                assert_eq!($test_result, $type::[< solve_ $index >](&parsed));
+                let input = aoc_lib::day_input(env!("CARGO_MANIFEST_DIR"))
+                    .expect("could not read input.txt")
+                    .expect("no input.txt");
+                assert_eq!($test_result, aoc_lib::[<solve_part_ $index>]::<$type>(&input).unwrap());
             }
         }
     };
 }
 
+/// Installs [CountingAllocator] if the `count-allocations` feature is enabled. The feature has to
+/// be checked here, as `cfg` in the expansion of [aoc_setup] would check the day's features.
+#[cfg(feature = "count-allocations")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! global_allocator {
+    () => {
+        #[global_allocator]
+        static ALLOCATOR: $crate::CountingAllocator = $crate::CountingAllocator;
+    };
+}
+
+#[cfg(not(feature = "count-allocations"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! global_allocator {
+    () => {};
+}
+
+/// Generates criterion benchmarks for parsing, preparing the shared intermediate (if there is one)
+/// and solving both parts, using both `sample.txt` and `input.txt`. The `input.txt` benchmarks are
+/// skipped if the crate has none. Meant to be the only contents of a day's `benches/bench.rs`.
+#[macro_export]
+macro_rules! aoc_bench {
+    ($type:ty) => {
+        fn bench_main(c: &mut criterion::Criterion) {
+            let input = $crate::day_input(env!("CARGO_MANIFEST_DIR")).expect("could not read input.txt");
+            if let Some(input) = input {
+                $crate::aoc_bench!(bench_impl c, $type, &input, "input", "");
+            }
+            $crate::aoc_bench!(bench_impl c, $type, include_str!("../sample.txt"), "sample input", " (sample input)");
+        }
+
+        criterion::criterion_group!(benches, bench_main);
+        criterion::criterion_main!(benches);
+    };
+    (bench_impl $c:ident, $type:ty, $input:expr, $input_name:literal, $solve_suffix:literal) => {{
+        use criterion::black_box;
+        use $crate::SharedAdventOfCode;
+
+        let input: &str = $input;
+        $c.bench_function(concat!("parse ", $input_name), |b| {
+            b.iter(|| <$type>::parse(black_box(input)).unwrap())
+        });
+
+        let parsed = <$type>::parse(input).unwrap();
+        if <$type>::HAS_SHARED_STAGE {
+            $c.bench_function(concat!("prepare", $solve_suffix), |b| {
+                b.iter(|| <$type>::prepare(black_box(&parsed)).unwrap())
+            });
+        }
+
+        let shared = <$type>::prepare(&parsed).unwrap();
+        $c.bench_function(concat!("solve 1", $solve_suffix), |b| {
+            b.iter(|| <$type>::part_1(black_box(&parsed), black_box(&shared)))
+        });
+
+        $c.bench_function(concat!("solve 2", $solve_suffix), |b| {
+            b.iter(|| <$type>::part_2(black_box(&parsed), black_box(&shared)))
+        });
+    }};
+}
+
+/// A solution to a day. The parts may have different kinds of answers, such as a count for part 1
+/// and a code for part 2; solutions with the same kind for both use the same type twice.
 pub trait AdventOfCode {
     type Input;
-    type Output;
+    type Output1;
+    type Output2;
 
     fn parse_input(s: &str) -> Self::Input;
-    fn solve_1(input: &Self::Input) -> Self::Output;
-    fn solve_2(input: &Self::Input) -> Self::Output;
+    fn solve_1(input: &Self::Input) -> Self::Output1;
+    fn solve_2(input: &Self::Input) -> Self::Output2;
+}
+
+/// A fallible version of [AdventOfCode], for solutions that report malformed input instead of
+/// panicking. Every [AdventOfCode] implementation is also a [TryAdventOfCode] that never fails.
+pub trait TryAdventOfCode {
+    type Input;
+    type Output1;
+    type Output2;
+    type Error: std::error::Error;
+
+    fn try_parse_input(s: &str) -> Result<Self::Input, Self::Error>;
+    fn try_solve_1(input: &Self::Input) -> Result<Self::Output1, Self::Error>;
+    fn try_solve_2(input: &Self::Input) -> Result<Self::Output2, Self::Error>;
+}
+
+impl<T: AdventOfCode> TryAdventOfCode for T {
+    type Input = T::Input;
+    type Output1 = T::Output1;
+    type Output2 = T::Output2;
+    type Error = Infallible;
+
+    fn try_parse_input(s: &str) -> Result<Self::Input, Self::Error> {
+        Ok(T::parse_input(s))
+    }
+
+    fn try_solve_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(T::solve_1(input))
+    }
+
+    fn try_solve_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(T::solve_2(input))
+    }
+}
+
+/// A solution whose parts both derive their answer from the same intermediate result, such as a
+/// search that is too expensive to do twice. The intermediate is computed once by
+/// [prepare](SharedAdventOfCode::prepare), which is timed as a stage of its own.
+///
+/// This is the most general shape of a solution, and the one that the runner and the macros
+/// work with. Every [TryAdventOfCode] implementation is also a [SharedAdventOfCode] without an
+/// intermediate.
+pub trait SharedAdventOfCode {
+    type Input;
+    type Shared;
+    type Output1;
+    type Output2;
+    type Error: std::error::Error;
+
+    /// Whether [prepare](SharedAdventOfCode::prepare) does any work worth measuring.
+    const HAS_SHARED_STAGE: bool = true;
+
+    fn parse(s: &str) -> Result<Self::Input, Self::Error>;
+    fn prepare(input: &Self::Input) -> Result<Self::Shared, Self::Error>;
+    fn part_1(input: &Self::Input, shared: &Self::Shared) -> Result<Self::Output1, Self::Error>;
+    fn part_2(input: &Self::Input, shared: &Self::Shared) -> Result<Self::Output2, Self::Error>;
+}
+
+impl<T: TryAdventOfCode> SharedAdventOfCode for T {
+    type Input = T::Input;
+    type Shared = ();
+    type Output1 = T::Output1;
+    type Output2 = T::Output2;
+    type Error = T::Error;
+
+    const HAS_SHARED_STAGE: bool = false;
+
+    fn parse(s: &str) -> Result<Self::Input, Self::Error> {
+        T::try_parse_input(s)
+    }
+
+    fn prepare(_: &Self::Input) -> Result<Self::Shared, Self::Error> {
+        Ok(())
+    }
+
+    fn part_1(input: &Self::Input, _: &Self::Shared) -> Result<Self::Output1, Self::Error> {
+        T::try_solve_1(input)
+    }
+
+    fn part_2(input: &Self::Input, _: &Self::Shared) -> Result<Self::Output2, Self::Error> {
+        T::try_solve_2(input)
+    }
+}
+
+/// Parses the input and solves just part 1 of a solution, without timing anything.
+pub fn solve_part_1<T: SharedAdventOfCode>(input: &str) -> Result<T::Output1, T::Error> {
+    let parsed = T::parse(input)?;
+    T::part_1(&parsed, &T::prepare(&parsed)?)
+}
+
+/// Parses the input and solves just part 2 of a solution, without timing anything.
+pub fn solve_part_2<T: SharedAdventOfCode>(input: &str) -> Result<T::Output2, T::Error> {
+    let parsed = T::parse(input)?;
+    T::part_2(&parsed, &T::prepare(&parsed)?)
 }
 
 /// Run and time just part 1 of a. AdventOfCode solution.
-pub fn run_part_1<T: AdventOfCode<Output = impl Display>>(_: T) {
-    let input = read_stdin();
-    let (parsed, parsed_time) = time(|| T::parse_input(&input));
-    let (solve_1, solve_1_time) = time(|| T::solve_1(&parsed));
+pub fn run_part_1<T: SharedAdventOfCode<Output1 = impl Display>>(key: DayKey, _: T) {
+    let mut args = Args::from_env();
+    let format = OutputFormat::from_args(&mut args).unwrap_or_else(|e| exit_with_error(e));
+    let options = SolveOptions::from_args(&mut args);
+    let input = read_stdin().unwrap_or_else(|e| exit_with_error(e));
+    let parse = measure(&options, || T::parse(&input)).unwrap_or_else(|e| exit_with_error(e));
+    let prepare =
+        measure(&options, || T::prepare(&parse.result)).unwrap_or_else(|e| exit_with_error(e));
+    let solve_1 = measure(&options, || T::part_1(&parse.result, &prepare.result))
+        .unwrap_or_else(|e| exit_with_error(e));
+    let prepare_time = T::HAS_SHARED_STAGE.then_some(prepare.time);
+
+    if format != OutputFormat::Human {
+        let answer = solve_1.result.to_string();
+        let memory = match (parse.memory, solve_1.memory) {
+            (Some(parse), Some(solve)) => Some([
+                parse,
+                prepare
+                    .memory
+                    .filter(|_| T::HAS_SHARED_STAGE)
+                    .unwrap_or_default(),
+                solve,
+            ]),
+            _ => None,
+        };
+        format.print_header();
+        format.print_records(&[Record {
+            year: key.year,
+            day: key.day,
+            part: 1,
+            input: None,
+            answer: &answer,
+            parse_ns: parse.time.as_nanos(),
+            prepare_ns: prepare_time.unwrap_or_default().as_nanos(),
+            solve_ns: solve_1.time.as_nanos(),
+            memory,
+        }]);
+        return;
+    }
 
-    println!("Solution to part 1: {}", solve_1);
+    println!("Solution to part 1: {}", solve_1.result);
 
-    println!("Parsing took: {:?}", parsed_time);
-    println!("Solving part 1 took: {:?}", solve_1_time);
+    match (&parse.stats, &prepare.stats, &solve_1.stats) {
+        (Some(parse), prepare, Some(solve_1)) => {
+            println!("Parsing took: {}", parse);
+            if let Some(prepare) = prepare.as_ref().filter(|_| T::HAS_SHARED_STAGE) {
+                println!("Preparing took: {}", prepare);
+            }
+            println!("Solving part 1 took: {}", solve_1);
+        }
+        _ => {
+            println!("Parsing took: {:?}", parse.time);
+            if let Some(prepare_time) = prepare_time {
+                println!("Preparing took: {:?}", prepare_time);
+            }
+            println!("Solving part 1 took: {:?}", solve_1.time);
+        }
+    }
+
+    match (parse.memory, prepare.memory, solve_1.memory) {
+        (Some(parse), prepare, Some(solve_1)) => {
+            println!("Parsing allocated: {}", parse);
+            if let Some(prepare) = prepare.filter(|_| T::HAS_SHARED_STAGE) {
+                println!("Preparing allocated: {}", prepare);
+            }
+            println!("Solving part 1 allocated: {}", solve_1);
+        }
+        _ if options.memory => {
+            eprintln!("Memory use is only counted when built with the count-allocations feature")
+        }
+        _ => {}
+    }
 }
 
-pub fn run<T: AdventOfCode<Output = impl Display>>(_: T) {
-    let input = read_stdin();
-    let (parsed, parsed_time) = time(|| T::parse_input(&input));
-    let (solve_1, solve_1_time) = time(|| T::solve_1(&parsed));
-    let (solve_2, solve_2_time) = time(|| T::solve_2(&parsed));
+pub fn run<T: ParallelSolution>(key: DayKey, solution: T) {
+    let mut args = Args::from_env();
+    let format = OutputFormat::from_args(&mut args).unwrap_or_else(|e| exit_with_error(e));
+    let options = SolveOptions::from_args(&mut args);
+    let baseline = BaselineConfig::from_args(&mut args).unwrap_or_else(|e| exit_with_error(e));
+    let input = read_stdin().unwrap_or_else(|e| exit_with_error(e));
+    let report = solve_with(solution, &input, &options).unwrap_or_else(|e| exit_with_error(e));
 
-    println!("Solution to part 1: {}", solve_1);
-    println!("Solution to part 2: {}", solve_2);
+    if format != OutputFormat::Human {
+        format.print_header();
+        format.print_records(&report.records(key));
+    } else {
+        println!("Solution to part 1: {}", report.solution_1);
+        println!("Solution to part 2: {}", report.solution_2);
 
-    println!("Parsing took: {:?}", parsed_time);
-    println!("Solving part 1 took: {:?}", solve_1_time);
-    println!("Solving part 2 took: {:?}", solve_2_time);
+        match &report.stats {
+            Some(stats) => {
+                println!("Parsing took: {}", stats.parse);
+                if let Some(prepare) = &stats.prepare {
+                    println!("Preparing took: {}", prepare);
+                }
+                println!("Solving part 1 took: {}", stats.solve_1);
+                println!("Solving part 2 took: {}", stats.solve_2);
+            }
+            None => {
+                println!("Parsing took: {:?}", report.parse_time);
+                if let Some(prepare_time) = report.prepare_time {
+                    println!("Preparing took: {:?}", prepare_time);
+                }
+                println!("Solving part 1 took: {:?}", report.solve_1_time);
+                println!("Solving part 2 took: {:?}", report.solve_2_time);
+            }
+        }
+
+        match &report.memory {
+            Some(memory) => {
+                println!("Parsing allocated: {}", memory.parse);
+                if let Some(prepare) = &memory.prepare {
+                    println!("Preparing allocated: {}", prepare);
+                }
+                println!("Solving part 1 allocated: {}", memory.solve_1);
+                println!("Solving part 2 allocated: {}", memory.solve_2);
+            }
+            None if options.memory => {
+                eprintln!(
+                    "Memory use is only counted when built with the count-allocations feature"
+                )
+            }
+            None => {}
+        }
+    }
+
+    if let Some(baseline) = baseline {
+        let regressions = baseline
+            .check(&[(key, &report)])
+            .unwrap_or_else(|e| exit_with_error(e));
+        if !regressions.is_empty() {
+            regressions.iter().for_each(|r| eprintln!("{}", r));
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Reads the `input.txt` in a day's crate directory, or returns `None` if there is none. Any
+/// other error, like an unreadable file, is returned as is.
+pub fn day_input(manifest_dir: &str) -> std::io::Result<Option<String>> {
+    match read_file(std::path::Path::new(manifest_dir).join("input.txt")) {
+        Ok(input) => Ok(Some(input)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Prints an error to stderr and exits with a non-zero exit code.
+fn exit_with_error(error: impl Display) -> ! {
+    eprintln!("Error: {}", error);
+    std::process::exit(1)
+}
+
+/// The answers to both parts of a solution, along with how long each stage took.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub solution_1: String,
+    pub solution_2: String,
+    pub parse_time: Duration,
+    /// How long computing the shared intermediate took, if the solution has one. See
+    /// [SharedAdventOfCode].
+    pub prepare_time: Option<Duration>,
+    pub solve_1_time: Duration,
+    pub solve_2_time: Duration,
+    /// Timing statistics for each stage, if the report was created by [solve_repeated]. The
+    /// durations above are then the medians.
+    pub stats: Option<ReportStats>,
+    /// What each stage allocated, if requested with [SolveOptions::memory].
+    pub memory: Option<ReportMemory>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReportStats {
+    pub parse: Stats,
+    pub prepare: Option<Stats>,
+    pub solve_1: Stats,
+    pub solve_2: Stats,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReportMemory {
+    pub parse: Allocations,
+    pub prepare: Option<Allocations>,
+    pub solve_1: Allocations,
+    pub solve_2: Allocations,
+}
+
+/// How [solve_with] runs and measures a solution.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SolveOptions {
+    /// Runs every stage repeatedly to gather timing statistics.
+    pub stats: Option<StatsConfig>,
+    /// Solves part 1 and part 2 on separate threads. Both only borrow the parsed input and the
+    /// shared intermediate, so they can run at the same time.
+    pub parallel_parts: bool,
+    /// Counts what every stage allocates during its first run. This requires
+    /// [CountingAllocator] to be the global allocator, see the `count-allocations` feature.
+    pub memory: bool,
+}
+
+impl SolveOptions {
+    /// Reads the `--stats`, `--parallel-parts` and `--memory` switches.
+    pub fn from_args(args: &mut Args) -> Self {
+        Self {
+            stats: args.take_flag("stats").then(StatsConfig::default),
+            parallel_parts: args.take_flag("parallel-parts"),
+            memory: args.take_flag("memory"),
+        }
+    }
+}
+
+/// A solution whose parts can be solved on different threads.
+pub trait ParallelSolution:
+    SharedAdventOfCode<
+    Input: Sync,
+    Shared: Sync,
+    Output1: Display + Send,
+    Output2: Display + Send,
+    Error: Send,
+>
+{
+}
+
+impl<T> ParallelSolution for T where
+    T: SharedAdventOfCode<
+        Input: Sync,
+        Shared: Sync,
+        Output1: Display + Send,
+        Output2: Display + Send,
+        Error: Send,
+    >
+{
+}
+
+/// Parses the input and solves both parts of a solution, timing each stage.
+pub fn solve<T: ParallelSolution>(solution: T, input: &str) -> Result<Report, T::Error> {
+    solve_with(solution, input, &SolveOptions::default())
+}
+
+/// Like [solve], but runs every stage repeatedly to gather timing statistics.
+pub fn solve_repeated<T: ParallelSolution>(
+    solution: T,
+    input: &str,
+    config: &StatsConfig,
+) -> Result<Report, T::Error> {
+    let options = SolveOptions {
+        stats: Some(*config),
+        ..SolveOptions::default()
+    };
+    solve_with(solution, input, &options)
+}
+
+/// Parses the input and solves both parts of a solution as configured by `options`.
+pub fn solve_with<T: ParallelSolution>(
+    _: T,
+    input: &str,
+    options: &SolveOptions,
+) -> Result<Report, T::Error> {
+    let parse = measure(options, || T::parse(input))?;
+    let prepare = measure(options, || T::prepare(&parse.result))?;
+
+    let solve_1 = || measure(options, || T::part_1(&parse.result, &prepare.result));
+    let solve_2 = || measure(options, || T::part_2(&parse.result, &prepare.result));
+    let (solve_1, solve_2) = if options.parallel_parts {
+        std::thread::scope(|s| {
+            let part_2 = s.spawn(solve_2);
+            let part_1 = solve_1();
+            (part_1, part_2.join().expect("part 2 panicked"))
+        })
+    } else {
+        (solve_1(), solve_2())
+    };
+    let (solve_1, solve_2) = (solve_1?, solve_2?);
+
+    let stats = match (parse.stats, solve_1.stats, solve_2.stats) {
+        (Some(parse), Some(solve_1), Some(solve_2)) => Some(ReportStats {
+            parse,
+            prepare: prepare.stats.filter(|_| T::HAS_SHARED_STAGE),
+            solve_1,
+            solve_2,
+        }),
+        _ => None,
+    };
+
+    let memory = match (parse.memory, solve_1.memory, solve_2.memory) {
+        (Some(parse), Some(solve_1), Some(solve_2)) => Some(ReportMemory {
+            parse,
+            prepare: prepare.memory.filter(|_| T::HAS_SHARED_STAGE),
+            solve_1,
+            solve_2,
+        }),
+        _ => None,
+    };
+
+    Ok(Report {
+        solution_1: solve_1.result.to_string(),
+        solution_2: solve_2.result.to_string(),
+        parse_time: parse.time,
+        prepare_time: T::HAS_SHARED_STAGE.then_some(prepare.time),
+        solve_1_time: solve_1.time,
+        solve_2_time: solve_2.time,
+        stats,
+        memory,
+    })
+}
+
+/// The result of a stage, along with how it performed.
+struct Measured<R> {
+    result: R,
+    time: Duration,
+    stats: Option<Stats>,
+    memory: Option<Allocations>,
+}
+
+/// Runs a stage once, counting its allocations if requested. It is then run repeatedly to gather
+/// statistics if requested, in which case the time is the median. Bails out before spending time
+/// on measurements if the stage fails.
+fn measure<R, E>(
+    options: &SolveOptions,
+    mut fun: impl FnMut() -> Result<R, E>,
+) -> Result<Measured<R>, E> {
+    let ((result, memory), time) = time(|| match options.memory {
+        true => count_allocations(&mut fun),
+        false => (fun(), None),
+    });
+    let result = result?;
+
+    Ok(match &options.stats {
+        Some(config) => {
+            let (_, stats) = time_repeated(config, fun);
+            Measured {
+                result,
+                time: stats.median,
+                stats: Some(stats),
+                memory,
+            }
+        }
+        None => Measured {
+            result,
+            time,
+            stats: None,
+            memory,
+        },
+    })
 }
 
 /// Executes some code and records the time it took to run
-pub fn time<T, F>(fun: F) -> (T, std::time::Duration)
+pub fn time<T, F>(fun: F) -> (T, Duration)
 where
     F: FnOnce() -> T,
 {
@@ -85,18 +663,73 @@ where
 }
 
 /// Reads stdin to a String
-pub fn read_stdin() -> String {
+pub fn read_stdin() -> std::io::Result<String> {
     use std::io::Read;
     let mut string = String::new();
-    std::io::stdin().lock().read_to_string(&mut string).unwrap();
-    string
+    std::io::stdin().lock().read_to_string(&mut string)?;
+    Ok(string)
 }
 
-pub fn read_file(filename: &str) -> String {
+pub fn read_file(filename: impl AsRef<std::path::Path>) -> std::io::Result<String> {
     use std::fs::File;
     use std::io::Read;
-    let mut file = File::open(filename).unwrap();
+    let mut file = File::open(filename)?;
     let mut string = String::new();
-    file.read_to_string(&mut string).unwrap();
-    string
+    file.read_to_string(&mut string)?;
+    Ok(string)
+}
+
+#[test]
+fn test_solve_shared() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static PREPARED: AtomicUsize = AtomicUsize::new(0);
+
+    struct Sum;
+    impl SharedAdventOfCode for Sum {
+        type Input = Vec<u32>;
+        type Shared = u32;
+        type Output1 = u32;
+        type Output2 = String;
+        type Error = ParseError;
+
+        fn parse(s: &str) -> Result<Self::Input, Self::Error> {
+            s.split(',').map(parse_number).collect()
+        }
+
+        fn prepare(input: &Self::Input) -> Result<Self::Shared, Self::Error> {
+            PREPARED.fetch_add(1, Ordering::Relaxed);
+            Ok(input.iter().sum())
+        }
+
+        fn part_1(_: &Self::Input, sum: &Self::Shared) -> Result<Self::Output1, Self::Error> {
+            Ok(*sum)
+        }
+
+        fn part_2(input: &Self::Input, sum: &Self::Shared) -> Result<Self::Output2, Self::Error> {
+            Ok(format!("{}/{}", sum, input.len()))
+        }
+    }
+
+    let report = solve(Sum, "1,2,3").unwrap();
+    assert_eq!(("6", "6/3"), (&*report.solution_1, &*report.solution_2));
+    assert!(report.prepare_time.is_some());
+    assert_eq!(1, PREPARED.load(Ordering::Relaxed));
+
+    struct Plain;
+    impl AdventOfCode for Plain {
+        type Input = ();
+        type Output1 = u8;
+        type Output2 = u8;
+
+        fn parse_input(_: &str) -> Self::Input {}
+        fn solve_1(_: &Self::Input) -> Self::Output1 {
+            1
+        }
+        fn solve_2(_: &Self::Input) -> Self::Output2 {
+            2
+        }
+    }
+
+    assert_eq!(None, solve(Plain, "").unwrap().prepare_time);
 }
@@ -0,0 +1,242 @@
+use std::{fmt::Write, str::FromStr};
+
+use crate::{args::Args, Allocations, DayKey, Report, ReportMemory};
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Prose meant to be read by people.
+    #[default]
+    Human,
+    /// One JSON object per line for every part that was solved.
+    Json,
+    /// A header, followed by one row for every part that was solved.
+    Csv,
+}
+
+impl OutputFormat {
+    /// Reads the format from a `--format` argument, falling back to the `AOC_FORMAT`
+    /// environment variable.
+    pub fn from_args(args: &mut Args) -> Result<Self, String> {
+        match args.take_value("format") {
+            Some(format) => format.parse(),
+            None => match std::env::var("AOC_FORMAT") {
+                Ok(format) => format.parse(),
+                Err(_) => Ok(Self::default()),
+            },
+        }
+    }
+
+    /// Prints whatever needs to come before the first record.
+    pub fn print_header(self) {
+        if self == Self::Csv {
+            println!("{}", Record::CSV_HEADER);
+        }
+    }
+
+    /// Prints the records of a report in this format. Does nothing for [OutputFormat::Human],
+    /// which callers lay out themselves.
+    pub fn print_records(self, records: &[Record]) {
+        for record in records {
+            match self {
+                Self::Human => {}
+                Self::Json => println!("{}", record.to_json()),
+                Self::Csv => println!("{}", record.to_csv()),
+            }
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!(
+                "unknown output format {:?}, expected human, json or csv",
+                s
+            )),
+        }
+    }
+}
+
+/// The result of solving a single part of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// The input from the day's `inputs` directory that was solved, `None` for `input.txt`. See
+    /// [NamedInput](crate::NamedInput).
+    pub input: Option<&'a str>,
+    pub answer: &'a str,
+    pub parse_ns: u128,
+    /// Time spent on the intermediate that both parts share, 0 if there is none.
+    pub prepare_ns: u128,
+    pub solve_ns: u128,
+    /// What parsing, preparing and solving allocated, if it was counted. See
+    /// [SolveOptions::memory](crate::SolveOptions::memory).
+    pub memory: Option<[Allocations; 3]>,
+}
+
+impl Record<'_> {
+    pub const CSV_HEADER: &'static str = "year,day,part,answer,parse_ns,prepare_ns,solve_ns,\
+        parse_allocs,parse_bytes,parse_peak,prepare_allocs,prepare_bytes,prepare_peak,\
+        solve_allocs,solve_bytes,solve_peak,input";
+
+    pub fn to_json(&self) -> String {
+        let answer = json_string(self.answer);
+        let input = self.input.map_or_else(|| "null".to_string(), json_string);
+
+        let memory = match self.memory {
+            Some([parse, prepare, solve]) => {
+                let stage = |a: Allocations| {
+                    format!(
+                        r#"{{"allocs":{},"bytes":{},"peak":{}}}"#,
+                        a.count, a.bytes, a.peak
+                    )
+                };
+                format!(
+                    r#"{{"parse":{},"prepare":{},"solve":{}}}"#,
+                    stage(parse),
+                    stage(prepare),
+                    stage(solve)
+                )
+            }
+            None => "null".to_string(),
+        };
+
+        format!(
+            r#"{{"year":{},"day":{},"part":{},"input":{},"answer":{},"parse_ns":{},"prepare_ns":{},"solve_ns":{},"memory":{}}}"#,
+            self.year,
+            self.day,
+            self.part,
+            input,
+            answer,
+            self.parse_ns,
+            self.prepare_ns,
+            self.solve_ns,
+            memory
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        // left empty if memory wasn't counted
+        let memory = match self.memory {
+            Some(stages) => stages
+                .map(|a| format!("{},{},{}", a.count, a.bytes, a.peak))
+                .join(","),
+            None => ",".repeat(8),
+        };
+
+        format!(
+            "{},{},{},{},{},{},{},{},{}",
+            self.year,
+            self.day,
+            self.part,
+            csv_field(self.answer),
+            self.parse_ns,
+            self.prepare_ns,
+            self.solve_ns,
+            memory,
+            self.input.map_or_else(String::new, csv_field)
+        )
+    }
+}
+
+/// Quotes and escapes a string for JSON.
+fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Quotes a CSV field if it needs to be.
+fn csv_field(s: &str) -> String {
+    if s.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+impl Report {
+    /// Splits this report into one record per part.
+    pub fn records(&self, key: DayKey) -> [Record<'_>; 2] {
+        let prepare_ns = self.prepare_time.unwrap_or_default().as_nanos();
+        let memory = |solve: fn(ReportMemory) -> Allocations| {
+            self.memory
+                .map(|m| [m.parse, m.prepare.unwrap_or_default(), solve(m)])
+        };
+        [
+            Record {
+                year: key.year,
+                day: key.day,
+                part: 1,
+                input: None,
+                answer: &self.solution_1,
+                parse_ns: self.parse_time.as_nanos(),
+                prepare_ns,
+                solve_ns: self.solve_1_time.as_nanos(),
+                memory: memory(|m| m.solve_1),
+            },
+            Record {
+                year: key.year,
+                day: key.day,
+                part: 2,
+                input: None,
+                answer: &self.solution_2,
+                parse_ns: self.parse_time.as_nanos(),
+                prepare_ns,
+                solve_ns: self.solve_2_time.as_nanos(),
+                memory: memory(|m| m.solve_2),
+            },
+        ]
+    }
+}
+
+#[test]
+fn test_record_formats() {
+    let record = Record {
+        year: 2021,
+        day: 13,
+        part: 2,
+        input: None,
+        answer: "A\"B,C",
+        parse_ns: 10,
+        prepare_ns: 15,
+        solve_ns: 20,
+        memory: None,
+    };
+    assert_eq!(
+        r#"{"year":2021,"day":13,"part":2,"input":null,"answer":"A\"B,C","parse_ns":10,"prepare_ns":15,"solve_ns":20,"memory":null}"#,
+        record.to_json()
+    );
+    assert_eq!(r#"2021,13,2,"A""B,C",10,15,20,,,,,,,,,,"#, record.to_csv());
+
+    let stage = |count, bytes, peak| Allocations { count, bytes, peak };
+    let record = Record {
+        input: Some("alice"),
+        memory: Some([stage(1, 64, 64), stage(0, 0, 0), stage(2, 96, 32)]),
+        ..record
+    };
+    assert_eq!(
+        r#"{"year":2021,"day":13,"part":2,"input":"alice","answer":"A\"B,C","parse_ns":10,"prepare_ns":15,"solve_ns":20,"memory":{"parse":{"allocs":1,"bytes":64,"peak":64},"prepare":{"allocs":0,"bytes":0,"peak":0},"solve":{"allocs":2,"bytes":96,"peak":32}}}"#,
+        record.to_json()
+    );
+    assert_eq!(
+        r#"2021,13,2,"A""B,C",10,15,20,1,64,64,0,0,0,2,96,32,alice"#,
+        record.to_csv()
+    );
+}
@@ -0,0 +1,50 @@
+use std::{num::NonZeroUsize, sync::Mutex, thread};
+
+/// The number of threads to use when none is given, based on the available cores.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Applies `fun` to every item on a pool of `threads` worker threads. Items are handed out in
+/// order as workers become free, and the results are returned in the same order as the items.
+pub fn parallel_map<I, R, F>(items: Vec<I>, threads: usize, fun: F) -> Vec<R>
+where
+    I: Send,
+    R: Send,
+    F: Fn(I) -> R + Sync,
+{
+    let count = items.len();
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results = Mutex::new((0..count).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|s| {
+        for _ in 0..threads.clamp(1, count.max(1)) {
+            s.spawn(|| loop {
+                // don't hold the lock while working, or the workers would run one at a time
+                let next = queue.lock().unwrap().next();
+                let Some((i, item)) = next else { break };
+                let result = fun(item);
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("every item is processed"))
+        .collect()
+}
+
+#[test]
+fn test_parallel_map_keeps_order() {
+    let items = (0..50u64).collect::<Vec<_>>();
+    let results = parallel_map(items, 4, |i| {
+        // make early items finish last
+        std::thread::sleep(std::time::Duration::from_micros(50 - i));
+        i * 2
+    });
+    assert_eq!((0..50).map(|i| i * 2).collect::<Vec<_>>(), results);
+    assert!(parallel_map(Vec::<u8>::new(), 4, |i| i).is_empty());
+}
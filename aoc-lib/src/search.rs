@@ -0,0 +1,233 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap},
+    hash::Hash,
+};
+
+use rustc_hash::FxHashMap;
+
+/// The cheapest way to reach a goal, as found by [dijkstra] or [astar].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: usize,
+    /// Every state along the way, starting with the start state and ending with the goal.
+    pub states: Vec<S>,
+}
+
+/// Finds the cheapest path from `start` to a state for which `is_goal` returns true.
+/// `successors` returns every state reachable from a state along with the cost of getting there.
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// Like [dijkstra], but explores states in order of their cost plus `heuristic`, an estimate of
+/// the remaining cost to reach a goal. The heuristic must never overestimate, or the path that is
+/// found may not be the cheapest.
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    // every state seen so far, with the cheapest known cost and the state it was reached from
+    let mut nodes: Vec<(S, usize, Option<usize>)> = vec![];
+    let mut indices = FxHashMap::default();
+    let mut heap = BinaryHeap::new();
+
+    heap.push((Reverse(heuristic(&start)), 0, 0));
+    indices.insert(start.clone(), 0);
+    nodes.push((start, 0, None));
+
+    while let Some((_, cost, idx)) = heap.pop() {
+        // if we've been here before in a cheaper way, skip
+        if cost > nodes[idx].1 {
+            continue;
+        }
+
+        if is_goal(&nodes[idx].0) {
+            return Some(reconstruct(&nodes, idx));
+        }
+
+        for (next, step_cost) in successors(&nodes[idx].0) {
+            let next_cost = cost + step_cost;
+            let next_idx = match indices.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_idx = *entry.get();
+                    if next_cost >= nodes[next_idx].1 {
+                        continue;
+                    }
+                    nodes[next_idx].1 = next_cost;
+                    nodes[next_idx].2 = Some(idx);
+                    next_idx
+                }
+                Entry::Vacant(entry) => {
+                    nodes.push((entry.key().clone(), next_cost, Some(idx)));
+                    *entry.insert(nodes.len() - 1)
+                }
+            };
+
+            let estimate = next_cost + heuristic(&nodes[next_idx].0);
+            heap.push((Reverse(estimate), next_cost, next_idx));
+        }
+    }
+
+    None
+}
+
+/// Like [dijkstra], for states that are already numbered from 0 up to `len`, such as the indices
+/// of a [Field2D](crate::utils::Field2D). Costs and paths are kept in plain vectors instead of a
+/// hash map, which is a lot faster for big searches.
+pub fn dijkstra_dense<I>(
+    len: usize,
+    start: usize,
+    successors: impl FnMut(usize) -> I,
+    is_goal: impl FnMut(usize) -> bool,
+) -> Option<Path<usize>>
+where
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    astar_dense(len, start, successors, |_| 0, is_goal)
+}
+
+/// Like [astar], for states that are numbered from 0 up to `len`. See [dijkstra_dense].
+pub fn astar_dense<I>(
+    len: usize,
+    start: usize,
+    mut successors: impl FnMut(usize) -> I,
+    mut heuristic: impl FnMut(usize) -> usize,
+    mut is_goal: impl FnMut(usize) -> bool,
+) -> Option<Path<usize>>
+where
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    // the cheapest known cost of every state, and the state it was reached from
+    let mut costs = vec![usize::MAX; len];
+    let mut previous = vec![usize::MAX; len];
+    let mut heap = BinaryHeap::new();
+
+    costs[start] = 0;
+    heap.push((Reverse(heuristic(start)), start));
+
+    // only the estimate is kept in the heap, which keeps its entries small
+    while let Some((Reverse(estimate), state)) = heap.pop() {
+        // if we've been here before in a cheaper way, skip
+        let cost = costs[state];
+        if estimate > cost + heuristic(state) {
+            continue;
+        }
+
+        if is_goal(state) {
+            let mut states = vec![state];
+            let mut current = state;
+            while previous[current] != usize::MAX {
+                current = previous[current];
+                states.push(current);
+            }
+            states.reverse();
+            return Some(Path { cost, states });
+        }
+
+        for (next, step_cost) in successors(state) {
+            let next_cost = cost + step_cost;
+            if next_cost < costs[next] {
+                costs[next] = next_cost;
+                previous[next] = state;
+                heap.push((Reverse(next_cost + heuristic(next)), next));
+            }
+        }
+    }
+
+    None
+}
+
+fn reconstruct<S: Clone>(nodes: &[(S, usize, Option<usize>)], goal: usize) -> Path<S> {
+    let mut states = vec![];
+    let mut current = Some(goal);
+    while let Some(idx) = current {
+        states.push(nodes[idx].0.clone());
+        current = nodes[idx].2;
+    }
+    states.reverse();
+
+    Path {
+        cost: nodes[goal].1,
+        states,
+    }
+}
+
+#[test]
+fn test_dijkstra() {
+    // 0 -> 1 is expensive, going around through 2 and 3 is cheaper
+    let edges = [
+        (0, 1, 10),
+        (0, 2, 1),
+        (2, 3, 2),
+        (3, 1, 3),
+        (1, 4, 1),
+        (5, 4, 1),
+    ];
+    let successors = |&node: &u8| {
+        edges
+            .iter()
+            .filter(move |e| e.0 == node)
+            .map(|e| (e.1, e.2))
+    };
+
+    let path = dijkstra(0, successors, |&n| n == 4).unwrap();
+    assert_eq!(7, path.cost);
+    assert_eq!(vec![0, 2, 3, 1, 4], path.states);
+
+    assert_eq!(None, dijkstra(0, successors, |&n| n == 5));
+}
+
+#[test]
+fn test_astar() {
+    // walk on an open 10x10 grid from the top left to the bottom right
+    let successors = |&(x, y): &(i32, i32)| {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+            .map(|p| (p, 1))
+    };
+    let heuristic = |&(x, y): &(i32, i32)| (9 - x + 9 - y) as usize;
+
+    let path = astar((0, 0), successors, heuristic, |&p| p == (9, 9)).unwrap();
+    assert_eq!(18, path.cost);
+    assert_eq!(19, path.states.len());
+    assert_eq!(Some(&(9, 9)), path.states.last());
+}
+
+#[test]
+fn test_dijkstra_dense() {
+    let edges = [
+        (0, 1, 10),
+        (0, 2, 1),
+        (2, 3, 2),
+        (3, 1, 3),
+        (1, 4, 1),
+        (5, 4, 1),
+    ];
+    let successors = |node: usize| {
+        edges
+            .iter()
+            .filter(move |e| e.0 == node)
+            .map(|e| (e.1, e.2))
+    };
+
+    let path = dijkstra_dense(6, 0, successors, |n| n == 4).unwrap();
+    assert_eq!(7, path.cost);
+    assert_eq!(vec![0, 2, 3, 1, 4], path.states);
+    assert_eq!(None, dijkstra_dense(6, 0, successors, |n| n == 5));
+}
@@ -0,0 +1,178 @@
+use std::{fmt::Display, hint::black_box, time::Duration};
+
+use crate::time;
+
+/// Controls how long [time_repeated] spends measuring a function.
+#[derive(Debug, Clone, Copy)]
+pub struct StatsConfig {
+    /// How long to run the function before measuring, to warm up caches and the branch predictor.
+    pub warmup: Duration,
+    /// Roughly how long to spend measuring. The iteration count is derived from this.
+    pub measurement: Duration,
+    pub min_iterations: usize,
+    pub max_iterations: usize,
+}
+
+impl Default for StatsConfig {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(50),
+            measurement: Duration::from_millis(200),
+            min_iterations: 5,
+            max_iterations: 100_000,
+        }
+    }
+}
+
+/// Statistics over the duration of repeated runs of a function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Calculates statistics over a set of samples, each of which took `batch` iterations.
+    fn from_samples(mut samples: Vec<Duration>, batch: u32) -> Self {
+        samples.sort_unstable();
+        let per_iteration = samples.iter().map(|&s| s / batch).collect::<Vec<_>>();
+
+        let mean = per_iteration.iter().sum::<Duration>() / per_iteration.len() as u32;
+        let variance = per_iteration
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / per_iteration.len() as f64;
+
+        Self {
+            iterations: samples.len() * batch as usize,
+            min: per_iteration[0],
+            median: per_iteration[per_iteration.len() / 2],
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} (min {:?}, mean {:?} ± {:?}, {} iterations)",
+            self.median, self.min, self.mean, self.stddev, self.iterations
+        )
+    }
+}
+
+/// Shortest time a single sample should take. Functions faster than this are run several times
+/// per sample, so the overhead and resolution of the clock don't dominate the measurement.
+const MIN_SAMPLE_TIME: Duration = Duration::from_micros(10);
+
+/// Executes some code repeatedly and records statistics on how long it took. Returns the result
+/// of the last run.
+pub fn time_repeated<T, F>(config: &StatsConfig, mut fun: F) -> (T, Stats)
+where
+    F: FnMut() -> T,
+{
+    // warm up, and estimate how long a single run takes while we're at it
+    let (mut ret, first) = time(&mut fun);
+    let mut warmup_runs = 1;
+    let mut warmup_time = first;
+    while warmup_time < config.warmup {
+        let (value, elapsed) = time(&mut fun);
+        ret = value;
+        warmup_runs += 1;
+        warmup_time += elapsed;
+    }
+    let estimate = (warmup_time / warmup_runs).max(Duration::from_nanos(1));
+
+    let iterations = (config.measurement.as_nanos() / estimate.as_nanos()) as usize;
+    let iterations = iterations.clamp(config.min_iterations, config.max_iterations);
+    // the samples are rounded up so at least min_iterations runs are measured, which overshoots
+    // max_iterations unless the batch is small enough. A batch of one always fits.
+    let batch = (MIN_SAMPLE_TIME.as_nanos() / estimate.as_nanos()) as usize;
+    let mut batch = batch.clamp(1, iterations.max(1));
+    while batch > 1 && iterations.div_ceil(batch) * batch > config.max_iterations {
+        batch -= 1;
+    }
+    let sample_count = iterations.div_ceil(batch).max(1);
+
+    let samples = (0..sample_count)
+        .map(|_| {
+            time(|| {
+                for _ in 1..batch {
+                    black_box(fun());
+                }
+                ret = fun();
+            })
+            .1
+        })
+        .collect();
+
+    (ret, Stats::from_samples(samples, batch as u32))
+}
+
+#[test]
+fn test_stats_from_samples() {
+    let samples = [4, 2, 6, 8, 10].map(Duration::from_micros).to_vec();
+    let stats = Stats::from_samples(samples, 2);
+    assert_eq!(10, stats.iterations);
+    assert_eq!(Duration::from_micros(1), stats.min);
+    assert_eq!(Duration::from_micros(3), stats.median);
+    assert_eq!(Duration::from_micros(3), stats.mean);
+    assert_eq!(Duration::from_nanos(1414), stats.stddev);
+}
+
+#[test]
+fn test_time_repeated_respects_iteration_bounds() {
+    let config = StatsConfig {
+        warmup: Duration::ZERO,
+        measurement: Duration::ZERO,
+        min_iterations: 3,
+        max_iterations: 3,
+    };
+    let mut count = 0;
+    let (ret, stats) = time_repeated(&config, || {
+        std::thread::sleep(Duration::from_micros(20));
+        count += 1;
+        count
+    });
+    assert_eq!(3, stats.iterations);
+    assert_eq!(4, ret, "one warmup run and three measured runs");
+
+    // takes about a third of a sample, so runs would be batched by three if five runs allowed it
+    let config = StatsConfig {
+        min_iterations: 5,
+        max_iterations: 5,
+        ..config
+    };
+    let mut count = 0;
+    let (ret, stats) = time_repeated(&config, || {
+        let start = std::time::Instant::now();
+        while start.elapsed() < MIN_SAMPLE_TIME / 3 {}
+        count += 1;
+        count
+    });
+    assert_eq!(5, stats.iterations);
+    assert_eq!(6, ret, "one warmup run and five measured runs");
+}
+
+#[test]
+fn test_time_repeated_batches_within_max_iterations() {
+    let config = StatsConfig {
+        warmup: Duration::ZERO,
+        measurement: Duration::from_millis(1),
+        min_iterations: 1,
+        max_iterations: 5,
+    };
+    // fast enough to be batched, so a whole batch would be far more than 5 iterations
+    let (_, stats) = time_repeated(&config, || black_box(1 + 1));
+    assert!(
+        (1..=5).contains(&stats.iterations),
+        "{} iterations",
+        stats.iterations
+    );
+}
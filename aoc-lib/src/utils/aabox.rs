@@ -0,0 +1,189 @@
+use super::{Discrete, Interval};
+
+/// An axis-aligned box in `N` dimensions: the points that lie within an interval on every axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AaBox<T, const N: usize> {
+    pub axes: [Interval<T>; N],
+}
+
+impl<T: Discrete, const N: usize> AaBox<T, N> {
+    pub const fn new(axes: [Interval<T>; N]) -> Self {
+        Self { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Interval::is_empty)
+    }
+
+    /// The number of points in the box.
+    pub fn volume(&self) -> u64 {
+        self.axes.iter().map(Interval::len).product()
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(axis, v)| axis.contains(v))
+    }
+
+    pub fn fits_inside(&self, other: &Self) -> bool {
+        self.axes
+            .iter()
+            .zip(&other.axes)
+            .all(|(a, b)| a.fits_inside(b))
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.axes
+            .iter()
+            .zip(&other.axes)
+            .all(|(a, b)| a.intersects(b))
+    }
+
+    /// The points in both boxes, or `None` if there are none.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut intersection = *self;
+        for (axis, other) in intersection.axes.iter_mut().zip(&other.axes) {
+            *axis = axis.intersection(other)?;
+        }
+        Some(intersection)
+    }
+
+    /// Cuts the points that are not in `other` into at most `2 * N` boxes, and passes them to
+    /// `out`. Going axis by axis, the slabs before and after `other` are cut off, and what remains
+    /// is narrowed down to the overlap on that axis.
+    pub fn difference(&self, other: &Self, out: &mut impl Extend<Self>) {
+        let Some(overlap) = self.intersection(other) else {
+            if !self.is_empty() {
+                out.extend([*self]);
+            }
+            return;
+        };
+
+        let mut rest = *self;
+        for axis in 0..N {
+            let slabs = rest.axes[axis].difference(&overlap.axes[axis]);
+            out.extend(slabs.into_iter().flatten().map(|slab| {
+                let mut piece = rest;
+                piece.axes[axis] = slab;
+                piece
+            }));
+            rest.axes[axis] = overlap.axes[axis];
+        }
+    }
+}
+
+/// A set of points, stored as boxes that don't overlap.
+#[derive(Debug, Clone)]
+pub struct BoxSet<T, const N: usize> {
+    boxes: Vec<AaBox<T, N>>,
+}
+
+impl<T, const N: usize> Default for BoxSet<T, N> {
+    fn default() -> Self {
+        Self { boxes: vec![] }
+    }
+}
+
+impl<T: Discrete, const N: usize> BoxSet<T, N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, to_add: AaBox<T, N>) {
+        if to_add.is_empty() {
+            return;
+        }
+
+        self.remove(to_add);
+        self.boxes.push(to_add);
+    }
+
+    pub fn remove(&mut self, to_remove: AaBox<T, N>) {
+        // going backwards, so the box that gets swapped into place has been handled already
+        for i in (0..self.boxes.len()).rev() {
+            if self.boxes[i].intersects(&to_remove) {
+                let old = self.boxes.swap_remove(i);
+                old.difference(&to_remove, &mut self.boxes);
+            }
+        }
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.boxes.iter().any(|b| b.contains(point))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    /// The number of points in the set.
+    pub fn volume(&self) -> u64 {
+        self.boxes.iter().map(AaBox::volume).sum()
+    }
+
+    /// The boxes that make up the set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &AaBox<T, N>> {
+        self.boxes.iter()
+    }
+}
+
+#[cfg(test)]
+fn cube(start: i32, end: i32) -> AaBox<i32, 3> {
+    AaBox::new([Interval::new(start, end); 3])
+}
+
+#[test]
+fn test_box_difference() {
+    let mut pieces = vec![];
+    cube(2, 7).difference(&cube(2, 7), &mut pieces);
+    assert!(pieces.is_empty(), "covers completely");
+
+    cube(2, 7).difference(&cube(20, 30), &mut pieces);
+    assert_eq!([cube(2, 7)], pieces.as_slice(), "no overlap");
+
+    let mut pieces = vec![];
+    let slab = AaBox::new([
+        Interval::new(0, 9),
+        Interval::new(0, 9),
+        Interval::new(4, 5),
+    ]);
+    cube(2, 7).difference(&slab, &mut pieces);
+    assert_eq!(
+        [
+            AaBox::new([
+                Interval::new(2, 7),
+                Interval::new(2, 7),
+                Interval::new(2, 3)
+            ]),
+            AaBox::new([
+                Interval::new(2, 7),
+                Interval::new(2, 7),
+                Interval::new(6, 7)
+            ]),
+        ],
+        pieces.as_slice(),
+        "covers middle"
+    );
+
+    let mut pieces = vec![];
+    cube(2, 7).difference(&cube(4, 5), &mut pieces);
+    assert_eq!(6, pieces.len(), "covers center");
+    assert_eq!(
+        6 * 6 * 6 - 2 * 2 * 2,
+        pieces.iter().map(AaBox::volume).sum::<u64>()
+    );
+}
+
+#[test]
+fn test_box_set() {
+    let mut set = BoxSet::new();
+    set.insert(cube(10, 12));
+    set.insert(cube(11, 13));
+    set.remove(cube(9, 11));
+    set.insert(cube(10, 10));
+    assert_eq!(39, set.volume());
+    assert!(set.contains([10, 10, 10]) && set.contains([13, 13, 13]));
+    assert!(!set.contains([11, 11, 11]));
+}
@@ -3,7 +3,67 @@ use std::{
     ops::{Index, IndexMut},
 };
 
-#[derive(Debug)]
+use crate::ParseError;
+
+/// Offsets of the orthogonal neighbours, in the same order as [Field2D::neighbour_indices].
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+/// Offsets of all eight neighbours, row by row from the top left.
+pub const EIGHT_WAY: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Offsets of a 3x3 window centered on a cell, row by row from the top left.
+pub const WINDOW_3X3: [(isize, isize); 9] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (0, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A cell type that can be parsed from a single character of a grid.
+pub trait FromChar: Sized {
+    /// Describes the characters that are accepted, used in error messages.
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+}
+
+/// Digits are parsed to their value, e.g. `'7'` becomes `7`.
+impl FromChar for u8 {
+    const EXPECTED: &'static str = "a digit";
+
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10).map(|d| d as u8)
+    }
+}
+
+/// `#` is parsed as `true` and `.` as `false`.
+impl FromChar for bool {
+    const EXPECTED: &'static str = "'#' or '.'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Field2D<T> {
     pub data: Vec<T>,
     pub stride: usize,
@@ -40,6 +100,57 @@ impl<T> Field2D<T> {
         ]
     }
 
+    /// The indices of all eight neighbours, in the order of [EIGHT_WAY].
+    pub fn eight_neighbour_indices(&self, idx: usize) -> [Option<usize>; 8] {
+        EIGHT_WAY.map(|offset| self.offset_index(idx, offset))
+    }
+
+    /// The index of the cell at `offset` from `idx`, or `None` if it's outside the field.
+    pub fn offset_index(&self, idx: usize, (dx, dy): (isize, isize)) -> Option<usize> {
+        let x = (idx % self.stride).checked_add_signed(dx)?;
+        let y = (idx / self.stride).checked_add_signed(dy)?;
+        (x < self.width() && y < self.height()).then(|| x + y * self.stride)
+    }
+
+    /// Visits the cells at each offset from `idx`, yielding the index of each cell along with
+    /// the cell, or `None` for both if it's outside the field.
+    pub fn stencil<'a>(
+        &'a self,
+        idx: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (Option<usize>, Option<&'a T>)> + 'a {
+        offsets.iter().map(move |&offset| {
+            let n = self.offset_index(idx, offset);
+            (n, n.map(|n| &self.data[n]))
+        })
+    }
+
+    /// Like [Field2D::offset_index], but wraps around the edges as if the field were a torus.
+    pub fn wrapping_offset_index(&self, idx: usize, (dx, dy): (isize, isize)) -> usize {
+        let x = (idx % self.stride) as isize + dx;
+        let y = (idx / self.stride) as isize + dy;
+        let x = x.rem_euclid(self.width() as isize) as usize;
+        let y = y.rem_euclid(self.height() as isize) as usize;
+        x + y * self.stride
+    }
+
+    /// Like [Field2D::neighbour_indices], but wraps around the edges.
+    pub fn wrapping_neighbour_indices(&self, idx: usize) -> [usize; 4] {
+        ORTHOGONAL.map(|offset| self.wrapping_offset_index(idx, offset))
+    }
+
+    /// Like [Field2D::stencil], but wraps around the edges so every cell exists.
+    pub fn wrapping_stencil<'a>(
+        &'a self,
+        idx: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, &'a T)> + 'a {
+        offsets.iter().map(move |&offset| {
+            let n = self.wrapping_offset_index(idx, offset);
+            (n, &self.data[n])
+        })
+    }
+
     pub fn stride_at(&self, idx: usize) -> &[T] {
         &self.data[self.stride * idx..self.stride * (idx + 1)]
     }
@@ -60,19 +171,51 @@ where
     }
 }
 
-impl Field2D<u8> {
-    pub fn parse(s: &str) -> Self {
-        Field2D {
-            data: s
-                .lines()
-                .map(|l| l.chars().map(|c| (c as u8) - b'0'))
-                .flatten()
-                .collect(),
-            stride: s.lines().next().unwrap().len(),
+impl<T> Field2D<T> {
+    /// Parses a grid with one cell per character, mapping each character with `fun`. Fails if
+    /// `fun` returns `None`, or if the rows don't all have the same length.
+    pub fn parse_with(s: &str, fun: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        Self::parse_cells(s, "a valid cell", fun)
+    }
+
+    fn parse_cells(
+        s: &str,
+        expected: &'static str,
+        mut fun: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut data = vec![];
+        let mut stride = None;
+        for line in s.lines() {
+            let width = line.chars().count();
+            match stride {
+                None => stride = Some(width),
+                Some(stride) if stride != width => {
+                    return Err(ParseError::new(line, format!("a row of {} cells", stride)))
+                }
+                Some(_) => {}
+            }
+
+            for (i, c) in line.char_indices() {
+                let cell =
+                    fun(c).ok_or_else(|| ParseError::new(&line[i..][..c.len_utf8()], expected))?;
+                data.push(cell);
+            }
+        }
+
+        match stride {
+            Some(stride) if stride > 0 => Ok(Self { data, stride }),
+            _ => Err(ParseError::new(s, "a grid")),
         }
     }
 }
 
+impl<T: FromChar> Field2D<T> {
+    /// Parses a grid with one cell per character, see [FromChar].
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        Self::parse_cells(s, T::EXPECTED, T::from_char)
+    }
+}
+
 impl<T> Display for Field2D<T>
 where
     T: Display + Copy,
@@ -102,3 +245,88 @@ impl<T> IndexMut<(usize, usize)> for Field2D<T> {
         &mut self.data[index.0 + index.1 * self.stride]
     }
 }
+
+#[test]
+fn test_parse() {
+    let field = Field2D::<u8>::parse("123\n456\n").unwrap();
+    assert_eq!(3, field.width());
+    assert_eq!(2, field.height());
+    assert_eq!(6, field[(2, 1)]);
+
+    let field = Field2D::parse_with("ab\nba", |c| Some(c == 'a')).unwrap();
+    assert_eq!(vec![true, false, false, true], field.data);
+}
+
+#[test]
+fn test_parse_errors() {
+    let input = "#.#\n.#\n";
+    let error = Field2D::<bool>::parse(input).unwrap_err().located(input);
+    assert_eq!(
+        "line 2, column 1: expected a row of 3 cells, found \".#\"",
+        error.to_string()
+    );
+
+    let input = "12\n3x\n";
+    let error = Field2D::<u8>::parse(input).unwrap_err().located(input);
+    assert_eq!(
+        "line 2, column 2: expected a digit, found \"x\"",
+        error.to_string()
+    );
+
+    assert!(Field2D::<u8>::parse("").is_err());
+}
+
+#[test]
+fn test_stencil() {
+    let field = Field2D::<u8>::parse("123\n456\n789").unwrap();
+    assert_eq!(
+        [None, None, None, None, Some(1), None, Some(3), Some(4)],
+        field.eight_neighbour_indices(0)
+    );
+
+    let window = field
+        .stencil(2, &WINDOW_3X3)
+        .map(|(n, c)| n.zip(c.copied()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            None,
+            None,
+            None,
+            Some((1, 2)),
+            Some((2, 3)),
+            None,
+            Some((4, 5)),
+            Some((5, 6)),
+            None
+        ],
+        window
+    );
+    assert!(field
+        .stencil(2, &WINDOW_3X3)
+        .all(|(n, c)| n.is_some() == c.is_some()));
+}
+
+#[test]
+fn test_wrapping() {
+    let field = Field2D::<u8>::parse("123\n456\n789").unwrap();
+    assert_eq!([6, 3, 2, 1], field.wrapping_neighbour_indices(0));
+    let window = field
+        .wrapping_stencil(8, &WINDOW_3X3)
+        .map(|(n, &c)| (n, c))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            (4, 5),
+            (5, 6),
+            (3, 4),
+            (7, 8),
+            (8, 9),
+            (6, 7),
+            (1, 2),
+            (2, 3),
+            (0, 1)
+        ],
+        window
+    );
+}
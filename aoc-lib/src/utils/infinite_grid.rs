@@ -0,0 +1,251 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut, RangeInclusive},
+};
+
+use super::Field2D;
+
+/// A grid without edges, addressed by signed `(x, y)` coordinates. Writing anywhere grows the grid
+/// as needed, and the bounding box of all written cells is tracked. The whole bounding box counts
+/// as written: cells inside it keep their value, even the ones that were never written to, and
+/// every cell outside of it holds the background value.
+#[derive(Debug, Clone)]
+pub struct InfiniteGrid<T> {
+    /// The allocated cells, row by row. Cells outside of the bounds always hold the background.
+    data: Vec<T>,
+    /// Coordinates of the first allocated cell.
+    origin: (isize, isize),
+    width: usize,
+    height: usize,
+    /// The smallest and largest coordinates that were written to. Before the first write, the
+    /// smallest ones are larger than the largest ones, so the bounds are empty and every write
+    /// can update them without checking for that first.
+    bounds: ((isize, isize), (isize, isize)),
+    background: T,
+}
+
+impl<T: Clone> InfiniteGrid<T> {
+    pub fn new(background: T) -> Self {
+        Self {
+            data: vec![],
+            origin: (0, 0),
+            width: 0,
+            height: 0,
+            bounds: EMPTY_BOUNDS,
+            background,
+        }
+    }
+
+    /// Places a field with its top left cell at `(0, 0)`, as if every cell was written to.
+    pub fn from_field(field: Field2D<T>, background: T) -> Self {
+        let (width, height) = (field.width(), field.height());
+        Self {
+            data: field.data,
+            origin: (0, 0),
+            width,
+            height,
+            bounds: if width > 0 && height > 0 {
+                ((0, 0), (width as isize - 1, height as isize - 1))
+            } else {
+                EMPTY_BOUNDS
+            },
+            background,
+        }
+    }
+
+    pub fn background(&self) -> &T {
+        &self.background
+    }
+
+    /// Changes the value of every cell outside of the bounding box. Cells inside it keep their
+    /// value, including ones that still hold the old background.
+    pub fn set_background(&mut self, background: T) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let pos = (self.origin.0 + x as isize, self.origin.1 + y as isize);
+                if !self.in_bounds(pos) {
+                    self.data[x + y * self.width] = background.clone();
+                }
+            }
+        }
+        self.background = background;
+    }
+
+    /// The x coordinates of the written cells, empty if nothing was written yet.
+    pub fn x_range(&self) -> RangeInclusive<isize> {
+        let (min, max) = self.bounds;
+        min.0..=max.0
+    }
+
+    /// The y coordinates of the written cells, empty if nothing was written yet.
+    pub fn y_range(&self) -> RangeInclusive<isize> {
+        let (min, max) = self.bounds;
+        min.1..=max.1
+    }
+
+    /// Allocates room for every cell from `min` to `max`, so writing to them doesn't grow the grid
+    /// one step at a time.
+    pub fn reserve(&mut self, min: (isize, isize), max: (isize, isize)) {
+        let (old_max_x, old_max_y) = (
+            self.origin.0 + self.width as isize - 1,
+            self.origin.1 + self.height as isize - 1,
+        );
+        if self.data.is_empty() {
+            self.resize((min.0, min.1), (max.0, max.1));
+        } else {
+            self.resize(
+                (min.0.min(self.origin.0), min.1.min(self.origin.1)),
+                (max.0.max(old_max_x), max.1.max(old_max_y)),
+            );
+        }
+    }
+
+    /// Iterates over the cells within the bounds, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((isize, isize), &T)> + '_ {
+        let (xs, ys) = (self.x_range(), self.y_range());
+        ys.flat_map(move |y| xs.clone().map(move |x| (x, y)))
+            .map(|pos| (pos, &self[pos]))
+    }
+
+    fn in_bounds(&self, (x, y): (isize, isize)) -> bool {
+        self.x_range().contains(&x) && self.y_range().contains(&y)
+    }
+
+    /// Allocates a cell that isn't allocated yet. The grid grows by at least its own size in any
+    /// direction that it needs to grow in, so writing cell by cell stays cheap.
+    #[cold]
+    fn grow_to_include(&mut self, (x, y): (isize, isize)) {
+        if self.data.is_empty() {
+            self.resize((x, y), (x, y));
+            return;
+        }
+
+        let (w, h) = (self.width as isize, self.height as isize);
+        let (max_x, max_y) = (self.origin.0 + w - 1, self.origin.1 + h - 1);
+        let min = (
+            if x < self.origin.0 {
+                x - w
+            } else {
+                self.origin.0
+            },
+            if y < self.origin.1 {
+                y - h
+            } else {
+                self.origin.1
+            },
+        );
+        let max = (
+            if x > max_x { x + w } else { max_x },
+            if y > max_y { y + h } else { max_y },
+        );
+        self.resize(min, max);
+    }
+
+    /// Reallocates the grid to span from `min` to `max`, which must include the old span.
+    fn resize(&mut self, min: (isize, isize), max: (isize, isize)) {
+        let width = (max.0 - min.0 + 1) as usize;
+        let height = (max.1 - min.1 + 1) as usize;
+        if (min, width, height) == (self.origin, self.width, self.height) {
+            return;
+        }
+
+        let mut data = vec![self.background.clone(); width * height];
+        let offset_x = (self.origin.0 - min.0) as usize;
+        let offset_y = (self.origin.1 - min.1) as usize;
+        for (y, row) in self.data.chunks_exact(self.width.max(1)).enumerate() {
+            let start = offset_x + (offset_y + y) * width;
+            data[start..start + self.width].clone_from_slice(row);
+        }
+
+        self.data = data;
+        self.origin = min;
+        self.width = width;
+        self.height = height;
+    }
+}
+
+impl<T> InfiniteGrid<T> {
+    /// The index in `data` of an allocated cell.
+    #[inline]
+    fn data_index(&self, (x, y): (isize, isize)) -> Option<usize> {
+        // cells before the origin wrap around to huge values
+        let x = x.wrapping_sub(self.origin.0) as usize;
+        let y = y.wrapping_sub(self.origin.1) as usize;
+        (x < self.width && y < self.height).then(|| x + y * self.width)
+    }
+}
+
+const EMPTY_BOUNDS: ((isize, isize), (isize, isize)) =
+    ((isize::MAX, isize::MAX), (isize::MIN, isize::MIN));
+
+impl<T> Index<(isize, isize)> for InfiniteGrid<T> {
+    type Output = T;
+
+    /// Reads a cell, which is the background if it was never written to.
+    fn index(&self, pos: (isize, isize)) -> &Self::Output {
+        match self.data_index(pos) {
+            Some(idx) => &self.data[idx],
+            None => &self.background,
+        }
+    }
+}
+
+impl<T: Clone> IndexMut<(isize, isize)> for InfiniteGrid<T> {
+    /// Writes a cell, growing the grid and its bounds to include it.
+    #[inline]
+    fn index_mut(&mut self, pos: (isize, isize)) -> &mut T {
+        let idx = match self.data_index(pos) {
+            Some(idx) => idx,
+            None => {
+                self.grow_to_include(pos);
+                self.data_index(pos).expect("the cell was just allocated")
+            }
+        };
+        let (min, max) = self.bounds;
+        self.bounds = (
+            (min.0.min(pos.0), min.1.min(pos.1)),
+            (max.0.max(pos.0), max.1.max(pos.1)),
+        );
+
+        &mut self.data[idx]
+    }
+}
+
+/// Shows the cells within the bounds, like [Field2D] does.
+impl<T> Display for InfiniteGrid<T>
+where
+    T: Display + Clone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in self.y_range() {
+            for x in self.x_range() {
+                write!(f, "{}", self[(x, y)])?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_grows_and_tracks_bounds() {
+    let mut grid = InfiniteGrid::new(0u8);
+    assert!(grid.x_range().is_empty());
+    assert_eq!(0, grid[(5, -5)]);
+
+    grid[(2, 1)] = 1;
+    grid[(-3, 4)] = 2;
+    grid[(0, -2)] += 3;
+    assert_eq!(-3..=2, grid.x_range());
+    assert_eq!(-2..=4, grid.y_range());
+    assert_eq!([1, 2, 3], [grid[(2, 1)], grid[(-3, 4)], grid[(0, -2)]]);
+    assert_eq!(6, grid.iter().map(|(_, &v)| v as usize).sum::<usize>());
+
+    grid.set_background(9);
+    assert_eq!(9, grid[(100, 100)]);
+    assert_eq!(0, grid[(-1, 0)], "cells within the bounds keep their value");
+
+    let grid = InfiniteGrid::from_field(Field2D::<u8>::parse("12\n34").unwrap(), 0);
+    assert_eq!("12\n34\n", grid.to_string());
+}
@@ -0,0 +1,240 @@
+use std::fmt::Debug;
+
+/// Integers that intervals can be made of: every value has a next and a previous value.
+pub trait Discrete: Copy + Ord {
+    fn succ(self) -> Self;
+    fn pred(self) -> Self;
+    /// The number of values from `start` up to and including `end`, which must not be smaller.
+    fn count(start: Self, end: Self) -> u64;
+}
+
+macro_rules! impl_discrete {
+    ($($type:ty),*) => {
+        $(
+            impl Discrete for $type {
+                fn succ(self) -> Self {
+                    self + 1
+                }
+
+                fn pred(self) -> Self {
+                    self - 1
+                }
+
+                fn count(start: Self, end: Self) -> u64 {
+                    (end as i128 - start as i128 + 1) as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// The values from `start` up to and including `end`. The interval is empty if `start` comes after
+/// `end`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Discrete> Interval<T> {
+    pub const fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The interval between two values, whichever comes first.
+    pub fn between(a: T, b: T) -> Self {
+        Self::new(a.min(b), a.max(b))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// The number of values in the interval.
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            T::count(self.start, self.end)
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn fits_inside(&self, other: &Self) -> bool {
+        self.start >= other.start && self.end <= other.end
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.start.max(other.start) <= self.end.min(other.end)
+    }
+
+    /// The values in both intervals, or `None` if there are none.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// The values that are in this interval but not in `other`: the part before `other`, and the
+    /// part after it.
+    pub fn difference(&self, other: &Self) -> [Option<Self>; 2] {
+        match self.intersection(other) {
+            None => [(!self.is_empty()).then_some(*self), None],
+            Some(overlap) => [
+                (self.start < overlap.start).then(|| Self::new(self.start, overlap.start.pred())),
+                (overlap.end < self.end).then(|| Self::new(overlap.end.succ(), self.end)),
+            ],
+        }
+    }
+}
+
+impl<T: Debug> Debug for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}..={:?})", self.start, self.end)
+    }
+}
+
+/// A set of values, stored as the sorted intervals that it is made of. Intervals that overlap or
+/// touch are merged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { intervals: vec![] }
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // the intervals that overlap or touch the new one, which all get merged into it
+        let from = self
+            .intervals
+            .partition_point(|i| i.end < interval.start && i.end.succ() < interval.start);
+        let to = self
+            .intervals
+            .partition_point(|i| i.start <= interval.end || i.start.pred() <= interval.end);
+
+        let mut merged = interval;
+        if from < to {
+            merged.start = merged.start.min(self.intervals[from].start);
+            merged.end = merged.end.max(self.intervals[to - 1].end);
+        }
+        self.intervals.splice(from..to, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let from = self.intervals.partition_point(|i| i.end < interval.start);
+        let to = self.intervals.partition_point(|i| i.start <= interval.end);
+        let rest = self.intervals[from..to]
+            .iter()
+            .flat_map(|i| i.difference(&interval))
+            .flatten()
+            .collect::<Vec<_>>();
+        self.intervals.splice(from..to, rest);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end < value);
+        self.intervals.get(idx).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    /// The intervals that make up the set, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+}
+
+impl<T: Discrete> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[test]
+fn test_interval_difference() {
+    let interval = Interval::new(5, 10);
+    assert_eq!(
+        [Some(interval), None],
+        interval.difference(&Interval::new(20, 30))
+    );
+    assert_eq!([None, None], interval.difference(&Interval::new(5, 10)));
+    assert_eq!(
+        [None, Some(Interval::new(8, 10))],
+        interval.difference(&Interval::new(0, 7))
+    );
+    assert_eq!(
+        [Some(Interval::new(5, 7)), None],
+        interval.difference(&Interval::new(8, 20))
+    );
+    assert_eq!(
+        [Some(Interval::new(5, 6)), Some(Interval::new(9, 10))],
+        interval.difference(&Interval::new(7, 8))
+    );
+    assert_eq!(6, interval.len());
+    assert_eq!(0, Interval::new(3, 2).len());
+    assert_eq!(Interval::new(2, 3), Interval::between(3, 2));
+}
+
+#[test]
+fn test_interval_set() {
+    let mut set = [(1, 3), (10, 12), (4, 5), (20, 20)]
+        .into_iter()
+        .map(|(start, end)| Interval::new(start, end))
+        .collect::<IntervalSet<i32>>();
+    assert_eq!(
+        [
+            Interval::new(1, 5),
+            Interval::new(10, 12),
+            Interval::new(20, 20)
+        ],
+        set.iter().copied().collect::<Vec<_>>().as_slice(),
+        "touching intervals are merged"
+    );
+
+    set.insert(Interval::new(5, 19));
+    assert_eq!(
+        [Interval::new(1, 20)],
+        set.iter().copied().collect::<Vec<_>>().as_slice()
+    );
+
+    set.remove(Interval::new(3, 7));
+    set.remove(Interval::new(20, 30));
+    assert_eq!(
+        [Interval::new(1, 2), Interval::new(8, 19)],
+        set.iter().copied().collect::<Vec<_>>().as_slice()
+    );
+    assert_eq!(14, set.len());
+    assert!(set.contains(8) && !set.contains(5) && !set.contains(20));
+}
@@ -1,3 +1,11 @@
+mod aabox;
 mod field2d;
+mod infinite_grid;
+mod interval;
+mod point;
 
-pub use field2d::Field2D;
+pub use aabox::{AaBox, BoxSet};
+pub use field2d::{Field2D, FromChar, EIGHT_WAY, ORTHOGONAL, WINDOW_3X3};
+pub use infinite_grid::InfiniteGrid;
+pub use interval::{Discrete, Interval, IntervalSet};
+pub use point::{Point2, Point3, Rotation, Signed};
@@ -0,0 +1,274 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use super::Field2D;
+
+/// Signed integers, which points need for distances and rotations.
+pub trait Signed:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($type:ty),*) => {
+        $(
+            impl Signed for $type {
+                const ZERO: Self = 0;
+
+                fn abs(self) -> Self {
+                    <$type>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Component-wise arithmetic, and scaling by a single value.
+macro_rules! impl_point {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T> $point<T> {
+            pub const fn new($($field: T),*) -> Self {
+                Self { $($field),* }
+            }
+        }
+
+        impl<T: Signed> $point<T> {
+            /// The distance when only moving along the axes.
+            pub fn manhattan_distance(self, other: Self) -> T {
+                T::ZERO $(+ (self.$field - other.$field).abs())*
+            }
+
+            /// The distance when also moving diagonally, like a king on a chess board.
+            pub fn chebyshev_distance(self, other: Self) -> T {
+                T::ZERO $(.max((self.$field - other.$field).abs()))*
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self { $($field: self.$field + rhs.$field),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self { $($field: self.$field - rhs.$field),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                Self { $($field: self.$field * rhs),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)*
+            }
+        }
+    };
+}
+
+impl_point!(Point2 { x, y });
+impl_point!(Point3 { x, y, z });
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(point: Point3<T>) -> Self {
+        (point.x, point.y, point.z)
+    }
+}
+
+impl<T: Copy> Point3<T> {
+    fn to_array(self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+/// One of the 24 ways to turn something in 3D space. Every axis is mapped onto another axis, and
+/// possibly flipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation {
+    /// For each axis of the result, the axis of the input that it takes its value from.
+    axes: [usize; 3],
+    /// For each axis of the result, whether the value is flipped.
+    flip: [bool; 3],
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Rotation {
+    pub const IDENTITY: Self = Self {
+        axes: [0, 1, 2],
+        flip: [false; 3],
+    };
+
+    /// All 24 rotations, starting with [Rotation::IDENTITY]. Mapping the axes in a way that would
+    /// mirror the space is left out.
+    pub fn all() -> impl Iterator<Item = Rotation> {
+        const PERMUTATIONS: [([usize; 3], bool); 6] = [
+            ([0, 1, 2], false),
+            ([0, 2, 1], true),
+            ([1, 0, 2], true),
+            ([1, 2, 0], false),
+            ([2, 0, 1], false),
+            ([2, 1, 0], true),
+        ];
+
+        PERMUTATIONS.into_iter().flat_map(|(axes, odd)| {
+            (0..8u8)
+                .map(|bits| [bits & 1 != 0, bits & 2 != 0, bits & 4 != 0])
+                // an odd number of flips undoes the mirroring of an odd permutation
+                .filter(move |flip| (flip.iter().filter(|&&f| f).count() % 2 == 1) == odd)
+                .map(move |flip| Rotation { axes, flip })
+        })
+    }
+
+    pub fn apply<T: Signed>(self, point: Point3<T>) -> Point3<T> {
+        let source = point.to_array();
+        let [x, y, z] = [0, 1, 2].map(|i| {
+            let value = source[self.axes[i]];
+            if self.flip[i] {
+                -value
+            } else {
+                value
+            }
+        });
+        Point3 { x, y, z }
+    }
+
+    /// The rotation that turns things back.
+    pub fn inverse(self) -> Self {
+        let mut inverse = Self::IDENTITY;
+        for i in 0..3 {
+            inverse.axes[self.axes[i]] = i;
+            inverse.flip[self.axes[i]] = self.flip[i];
+        }
+        inverse
+    }
+
+    /// The rotation that turns by `self` first, and then by `next`.
+    pub fn then(self, next: Self) -> Self {
+        Self {
+            axes: next.axes.map(|axis| self.axes[axis]),
+            flip: [0, 1, 2].map(|i| next.flip[i] != self.flip[next.axes[i]]),
+        }
+    }
+}
+
+/// Conversions between points and the indices that most [Field2D] methods work with.
+impl<T> Field2D<T> {
+    /// The index of a point, or `None` if it's outside the field.
+    pub fn index_of(&self, point: Point2<isize>) -> Option<usize> {
+        let (x, y) = (
+            usize::try_from(point.x).ok()?,
+            usize::try_from(point.y).ok()?,
+        );
+        (x < self.width() && y < self.height()).then(|| x + y * self.stride)
+    }
+
+    pub fn point_of(&self, idx: usize) -> Point2<isize> {
+        Point2::new((idx % self.stride) as isize, (idx / self.stride) as isize)
+    }
+}
+
+#[test]
+fn test_point_ops() {
+    let (a, b) = (Point2::new(1, -2), Point2::new(-3, 5));
+    assert_eq!(Point2::new(-2, 3), a + b);
+    assert_eq!(Point2::new(4, -7), a - b);
+    assert_eq!(Point2::new(3, -6), a * 3);
+    assert_eq!(11, a.manhattan_distance(b));
+    assert_eq!(7, a.chebyshev_distance(b));
+    assert_eq!(
+        6,
+        Point3::new(0, 0, 0).manhattan_distance(Point3::new(1, -2, 3))
+    );
+
+    let field = Field2D::new(4, 3, 0u8);
+    assert_eq!(Some(9), field.index_of(Point2::new(1, 2)));
+    assert_eq!(None, field.index_of(Point2::new(-1, 2)));
+    assert_eq!(None, field.index_of(Point2::new(4, 0)));
+    assert_eq!(Point2::new(1, 2), field.point_of(9));
+}
+
+#[test]
+fn test_rotations() {
+    let point = Point3::new(1i16, 2, 3);
+    let rotated = Rotation::all()
+        .map(|r| r.apply(point))
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(24, rotated.len());
+    assert_eq!(Some(Rotation::IDENTITY), Rotation::all().next());
+
+    // a quarter turn around the z axis
+    let turn = Rotation {
+        axes: [1, 0, 2],
+        flip: [true, false, false],
+    };
+    assert_eq!(Point3::new(-2, 1, 3), turn.apply(point));
+
+    for r in Rotation::all() {
+        assert_eq!(point, r.inverse().apply(r.apply(point)));
+        assert_eq!(turn.apply(r.apply(point)), r.then(turn).apply(point));
+    }
+}
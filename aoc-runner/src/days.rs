@@ -0,0 +1,85 @@
+use std::error::Error;
+
+use aoc_lib::{solve_with, DayKey, Report, SolveOptions};
+
+/// Solves a day with the given options. The error can be sent back from a worker thread.
+type SolveFn = fn(&str, &SolveOptions) -> Result<Report, Box<dyn Error + Send + Sync>>;
+
+/// A day that the runner knows how to solve.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub key: DayKey,
+    pub solve: SolveFn,
+    /// Other ways to solve the day, by name, which `--engine` picks from.
+    pub engines: &'static [(&'static str, SolveFn)],
+}
+
+impl Day {
+    /// This day solved by another engine, or `None` if it has no engine by that name.
+    pub fn with_engine(&self, name: &str) -> Option<Day> {
+        let &(_, solve) = self.engines.iter().find(|(n, _)| *n == name)?;
+        Some(Day { solve, ..*self })
+    }
+}
+
+macro_rules! days {
+    ($(
+        ($year:literal, $day:literal) => $type:path
+        $({ $($engine:literal => $engine_type:path),* $(,)? })?
+    ),* $(,)?) => {
+        /// Every solution in the workspace, ordered by year and day.
+        pub const DAYS: &[Day] = &[
+            $(Day {
+                key: DayKey::new($year, $day),
+                solve: |input, options| Ok(solve_with($type, input, options)?),
+                engines: &[$($((
+                    $engine,
+                    |input, options| Ok(solve_with($engine_type, input, options)?),
+                )),*)?],
+            },)*
+        ];
+    };
+}
+
+days! {
+    (2021, 1) => aoc2021_day_1::Day1,
+    (2021, 2) => aoc2021_day_2::Day2,
+    (2021, 3) => aoc2021_day_3::Day3,
+    (2021, 4) => aoc2021_day_4::Day4,
+    (2021, 5) => aoc2021_day_5::Day5,
+    (2021, 6) => aoc2021_day_6::Day6,
+    (2021, 7) => aoc2021_day_7::Day7,
+    (2021, 8) => aoc2021_day_8::Day8,
+    (2021, 9) => aoc2021_day_9::Day9,
+    (2021, 10) => aoc2021_day_10::Day10,
+    (2021, 11) => aoc2021_day_11::Day11,
+    (2021, 12) => aoc2021_day_12::Day12,
+    (2021, 13) => aoc2021_day_13::Day13,
+    (2021, 14) => aoc2021_day_14::Day14,
+    (2021, 15) => aoc2021_day_15::Day15,
+    (2021, 16) => aoc2021_day_16::Day16,
+    (2021, 17) => aoc2021_day_17::Day17,
+    (2021, 18) => aoc2021_day_18::Day18,
+    (2021, 19) => aoc2021_day_19::Day19,
+    (2021, 20) => aoc2021_day_20::Day20,
+    (2021, 21) => aoc2021_day_21::Day21,
+    (2021, 22) => aoc2021_day_22::Day22 { "signed" => aoc2021_day_22::Day22Signed },
+    (2021, 23) => aoc2021_day_23::Day23,
+}
+
+pub fn find(key: DayKey) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.key == key)
+}
+
+/// Every day of a year, or of all years if none is given.
+pub fn in_year(year: Option<u16>) -> Vec<Day> {
+    DAYS.iter()
+        .copied()
+        .filter(|d| year.is_none_or(|year| d.key.year == year))
+        .collect()
+}
+
+/// The most recent year that has a solution.
+pub fn latest_year() -> u16 {
+    DAYS.iter().map(|d| d.key.year).max().unwrap_or_default()
+}
@@ -0,0 +1,539 @@
+use std::{
+    path::{Path, PathBuf},
+    process::exit,
+    time::Duration,
+};
+
+use aoc_lib::{
+    AnswerStore, Args, BaselineConfig, Client, DayKey, NamedInput, OutputFormat, Record, Report,
+    SolveOptions, Stats, Verdict,
+};
+use days::Day;
+
+mod days;
+mod scaffold;
+
+// counting is cheap enough to leave on once built in, and is only reported with --memory
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc_lib::CountingAllocator = aoc_lib::CountingAllocator;
+
+const USAGE: &str = "Usage: aoc run <day|all> [--format human|json|csv] [--stats] [--memory] \
+    [--parallel [--threads <n>]] [--parallel-parts] [--baseline <path> [--threshold <percent>] [--update-baseline]]
+       aoc submit <day> <part>
+       aoc check <day|all> [--parallel [--threads <n>]]
+       aoc new <day>
+
+All commands take [--year <year|all>] to choose the event, which defaults to the latest one.
+Run, check and submit take [--engine <name>] to solve a single day in another way, if it has one.
+Run and submit take [--answers <path>] to choose where answers are stored.";
+
+fn main() {
+    let mut args = Args::from_env();
+    let format = OutputFormat::from_args(&mut args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        exit(1);
+    });
+    let options = SolveOptions::from_args(&mut args);
+    let threads = threads_from_args(&mut args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        exit(1);
+    });
+    let baseline = BaselineConfig::from_args(&mut args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        exit(1);
+    });
+
+    let year = year_from_args(&mut args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        exit(1);
+    });
+    let engine = args.take_value("engine");
+    let answers = args
+        .take_value("answers")
+        .or_else(|| std::env::var("AOC_ANSWERS").ok())
+        .map_or_else(|| workspace_path("answers.csv"), PathBuf::from);
+
+    let engine = engine.as_deref();
+    let days = match args.positional().as_slice() {
+        ["run", "all"] => all_days(year, engine),
+        ["run", day] => vec![find_day(year, day, engine)],
+        ["new", day] => new_day(year, day),
+        ["check", "all"] => check(&all_days(year, engine), threads),
+        ["check", day] => check(&[find_day(year, day, engine)], threads),
+        ["submit", day, part @ ("1" | "2")] => submit(
+            &find_day(year, day, engine),
+            part.parse().unwrap(),
+            &answers,
+        ),
+        _ => {
+            eprintln!("{}", USAGE);
+            exit(1);
+        }
+    };
+
+    let client = Client::from_env();
+
+    // every input of every day: `input.txt`, followed by the ones in the `inputs` directory
+    let mut failed = false;
+    let mut jobs = vec![];
+    for &day in &days {
+        jobs.push((day, None));
+        for input in load_named_inputs(day) {
+            match input {
+                Ok(input) => jobs.push((day, Some(input))),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    // inputs are independent, so they can be solved on a pool while the output stays in order
+    let (results, wall_time) = aoc_lib::time(|| {
+        aoc_lib::parallel_map(jobs, threads, |(day, input)| match input {
+            None => (day.key, None, solve_day(&day, &options, client.as_ref())),
+            Some(input) => {
+                let result = (day.solve)(&input.input, &options)
+                    .map_err(|e| format!("Could not solve {} {}: {}", day.key, input.name, e));
+                (day.key, Some(input), result)
+            }
+        })
+    });
+
+    let mut rows = vec![];
+    for (key, input, result) in results {
+        match result {
+            Ok(report) => {
+                let mismatches = input.as_ref().map(|i| i.mismatches(&report));
+                for mismatch in mismatches.iter().flatten() {
+                    eprintln!("{} {}", key, mismatch);
+                    failed = true;
+                }
+                rows.push((key, input.map(|i| i.name), report));
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                failed = true;
+            }
+        }
+    }
+
+    if format == OutputFormat::Human {
+        print_table(&rows);
+        if options.memory {
+            if rows.iter().any(|(_, _, report)| report.memory.is_some()) {
+                println!();
+                print_memory_table(&rows);
+            } else {
+                eprintln!(
+                    "Memory use is only counted when built with the count-allocations feature"
+                );
+            }
+        }
+        if threads > 1 {
+            println!();
+            println!("Wall clock: {:?} on {} threads", wall_time, threads);
+        }
+    } else {
+        format.print_header();
+        for (key, input, report) in &rows {
+            let records = report.records(*key).map(|record| Record {
+                input: input.as_deref(),
+                ..record
+            });
+            format.print_records(&records);
+        }
+    }
+
+    // the answer store and the baseline only know about `input.txt`
+    let main_rows = rows
+        .iter()
+        .filter(|(_, input, _)| input.is_none())
+        .map(|(key, _, report)| (*key, report))
+        .collect::<Vec<_>>();
+
+    match AnswerStore::load(&answers) {
+        Ok(store) => {
+            for (key, report) in &main_rows {
+                for record in report.records(*key) {
+                    let verdict = store.verdict(*key, record.part, record.answer);
+                    if matches!(verdict, Some(v) if v != Verdict::Correct) {
+                        eprintln!(
+                            "{} part {}: {} is known to be wrong",
+                            key, record.part, record.answer
+                        );
+                        failed = true;
+                    }
+                }
+            }
+        }
+        Err(e) => eprintln!("Could not read answers {}: {}", answers.display(), e),
+    }
+
+    if let Some(baseline) = baseline {
+        match baseline.check(&main_rows) {
+            Ok(regressions) => {
+                for regression in &regressions {
+                    eprintln!("{}", regression);
+                    failed = true;
+                }
+            }
+            Err(e) => {
+                eprintln!(
+                    "Could not check baseline {}: {}",
+                    baseline.path.display(),
+                    e
+                );
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        exit(1);
+    }
+}
+
+/// Reads `--parallel` and `--threads <n>`, returning how many days to solve at the same time.
+fn threads_from_args(args: &mut Args) -> Result<usize, String> {
+    let parallel = args.take_flag("parallel");
+    match args.take_value("threads") {
+        Some(_) if !parallel => Err("--threads requires --parallel".into()),
+        Some(t) => match t.parse() {
+            Ok(threads) if threads > 0 => Ok(threads),
+            _ => Err(format!("invalid thread count {:?}", t)),
+        },
+        None if parallel => Ok(aoc_lib::default_threads()),
+        None => Ok(1),
+    }
+}
+
+/// Reads `--year <year|all>`, defaulting to the latest year. Returns `None` for all years.
+fn year_from_args(args: &mut Args) -> Result<Option<u16>, String> {
+    match args.take_value("year").as_deref() {
+        None => Ok(Some(days::latest_year())),
+        Some("all") => Ok(None),
+        Some(year) => match year.parse() {
+            Ok(year) => Ok(Some(year)),
+            Err(_) => Err(format!("invalid year {:?}", year)),
+        },
+    }
+}
+
+fn day_key(year: Option<u16>, day: &str) -> DayKey {
+    let Some(year) = year else {
+        eprintln!("A single day needs a single year");
+        exit(1);
+    };
+    match day.parse() {
+        Ok(day @ 1..=25) => DayKey::new(year, day),
+        _ => {
+            eprintln!("Invalid day: {}", day);
+            exit(1);
+        }
+    }
+}
+
+/// Finds a day, solved by the given engine if there is one.
+fn find_day(year: Option<u16>, day: &str, engine: Option<&str>) -> Day {
+    let key = day_key(year, day);
+    let day = days::find(key).unwrap_or_else(|| {
+        eprintln!("Unknown day: {}", key);
+        exit(1);
+    });
+    let Some(engine) = engine else {
+        return *day;
+    };
+
+    day.with_engine(engine).unwrap_or_else(|| {
+        let names = day
+            .engines
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+        match names.as_slice() {
+            [] => eprintln!("{} has no other engines", key),
+            names => eprintln!(
+                "{} has no engine {:?}, try one of: {}",
+                key,
+                engine,
+                names.join(", ")
+            ),
+        }
+        exit(1);
+    })
+}
+
+fn all_days(year: Option<u16>, engine: Option<&str>) -> Vec<Day> {
+    if engine.is_some() {
+        eprintln!("--engine needs a single day");
+        exit(1);
+    }
+    days::in_year(year)
+}
+
+/// Creates and registers the crate for a new day.
+fn new_day(year: Option<u16>, day: &str) -> ! {
+    let key = day_key(year, day);
+    match scaffold::create_day(&workspace_path(""), key, Client::from_env().as_ref()) {
+        Ok(()) => {
+            println!("Created {}", key.crate_dir());
+            exit(0);
+        }
+        Err(e) => {
+            eprintln!("Could not create {}: {}", key, e);
+            exit(1);
+        }
+    }
+}
+
+/// Solves every input in the `inputs` directories of the given days, and compares the solutions
+/// with their answers.
+fn check(days: &[Day], threads: usize) -> ! {
+    let mut failed = false;
+    let mut inputs = vec![];
+    for &day in days {
+        for input in load_named_inputs(day) {
+            match input {
+                Ok(input) => inputs.push((day, input)),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    failed = true;
+                }
+            }
+        }
+    }
+    if inputs.is_empty() {
+        eprintln!("No inputs found");
+    }
+
+    let results = aoc_lib::parallel_map(inputs, threads, |(day, input)| {
+        let result = (day.solve)(&input.input, &SolveOptions::default());
+        let mismatches = result.map(|report| input.mismatches(&report));
+        (day.key, input.name, mismatches.map_err(|e| e.to_string()))
+    });
+
+    for (key, name, result) in results {
+        match result {
+            Ok(mismatches) if mismatches.is_empty() => println!("{} {}: ok", key, name),
+            Ok(mismatches) => {
+                mismatches.iter().for_each(|m| println!("{} {}", key, m));
+                failed = true;
+            }
+            Err(e) => {
+                println!("{} {}: could not solve: {}", key, name, e);
+                failed = true;
+            }
+        }
+    }
+
+    exit(failed as i32);
+}
+
+/// Loads the inputs in a day's `inputs` directory. Each one that can't be read is an error of its
+/// own, so the others can still be solved.
+fn load_named_inputs(day: Day) -> Vec<Result<NamedInput, String>> {
+    let dir = workspace_path(&day.key.crate_dir());
+    match NamedInput::names(&dir) {
+        Ok(names) => names
+            .iter()
+            .map(|name| {
+                NamedInput::load(&dir, name)
+                    .map_err(|e| format!("Could not read input {} for {}: {}", name, day.key, e))
+            })
+            .collect(),
+        Err(e) => vec![Err(format!("Could not read inputs for {}: {}", day.key, e))],
+    }
+}
+
+/// Solves a day and submits the answer to one of its parts, unless the answer store already
+/// knows whether it is correct.
+fn submit(day: &Day, part: u8, answers: &Path) -> ! {
+    let client = Client::from_env().unwrap_or_else(|| {
+        eprintln!("Submitting requires a session token in AOC_SESSION");
+        exit(1);
+    });
+    let mut store = AnswerStore::load(answers).unwrap_or_else(|e| {
+        eprintln!("Could not read answers {}: {}", answers.display(), e);
+        exit(1);
+    });
+    let report = solve_day(day, &SolveOptions::default(), Some(&client)).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        exit(1);
+    });
+    let answer = match part {
+        1 => &report.solution_1,
+        _ => &report.solution_2,
+    };
+
+    let key = day.key;
+    match store.verdict(key, part, answer) {
+        Some(Verdict::Correct) => {
+            println!(
+                "{} part {}: {} is already known to be correct",
+                key, part, answer
+            );
+            exit(0);
+        }
+        Some(verdict) => {
+            eprintln!(
+                "{} part {}: not submitting {}, it is known to be {}",
+                key, part, answer, verdict
+            );
+            exit(1);
+        }
+        None => {}
+    }
+
+    let verdict = client.submit(key, part, answer).unwrap_or_else(|e| {
+        eprintln!("Could not submit {} part {}: {}", key, part, e);
+        exit(1);
+    });
+    println!("{} part {}: {} is {}", key, part, answer, verdict);
+
+    store.record(key, part, answer, verdict);
+    if let Err(e) = store.save(answers) {
+        eprintln!("Could not save answers {}: {}", answers.display(), e);
+        exit(1);
+    }
+    exit(if verdict == Verdict::Correct { 0 } else { 1 });
+}
+
+fn solve_day(day: &Day, options: &SolveOptions, client: Option<&Client>) -> Result<Report, String> {
+    let path = input_path(day);
+    let input = match client {
+        Some(client) => client.cached_input(&path, day.key),
+        None => aoc_lib::read_file(path),
+    }
+    .map_err(|e| format!("Could not read input for {}: {}", day.key, e))?;
+    (day.solve)(&input, options).map_err(|e| format!("Could not solve {}: {}", day.key, e))
+}
+
+/// The location of a day's `input.txt`, relative to the workspace root. Missing inputs are
+/// downloaded to here if `AOC_SESSION` is set.
+fn input_path(day: &Day) -> PathBuf {
+    workspace_path(&format!("{}/input.txt", day.key.crate_dir()))
+}
+
+fn workspace_path(path: &str) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "..", path].iter().collect()
+}
+
+fn print_table(rows: &[(DayKey, Option<String>, Report)]) {
+    let header = [
+        "Year",
+        "Day",
+        "Part 1",
+        "Part 2",
+        "Parsing",
+        "Preparing",
+        "Solving 1",
+        "Solving 2",
+    ];
+    let mut cells = rows
+        .iter()
+        .map(|(key, input, report)| {
+            [
+                key.year.to_string(),
+                day_cell(*key, input),
+                report.solution_1.clone(),
+                report.solution_2.clone(),
+                format_time(report.parse_time, report.stats.map(|s| s.parse)),
+                report.prepare_time.map_or_else(String::new, |time| {
+                    format_time(time, report.stats.and_then(|s| s.prepare))
+                }),
+                format_time(report.solve_1_time, report.stats.map(|s| s.solve_1)),
+                format_time(report.solve_2_time, report.stats.map(|s| s.solve_2)),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let total = rows.iter().fold(Duration::ZERO, |acc, (_, _, report)| {
+        acc + report.parse_time
+            + report.prepare_time.unwrap_or_default()
+            + report.solve_1_time
+            + report.solve_2_time
+    });
+    cells.push([
+        "Total".into(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        format!("{:?}", total),
+    ]);
+
+    print_cells(header, &cells);
+}
+
+/// Prints what every stage allocated, for the rows that have counts.
+fn print_memory_table(rows: &[(DayKey, Option<String>, Report)]) {
+    let header = [
+        "Year",
+        "Day",
+        "Parsing",
+        "Preparing",
+        "Solving 1",
+        "Solving 2",
+    ];
+    let cells = rows
+        .iter()
+        .filter_map(|(key, input, report)| {
+            let memory = report.memory?;
+            Some([
+                key.year.to_string(),
+                day_cell(*key, input),
+                memory.parse.to_string(),
+                memory.prepare.map_or_else(String::new, |m| m.to_string()),
+                memory.solve_1.to_string(),
+                memory.solve_2.to_string(),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    print_cells(header, &cells);
+}
+
+/// The day, followed by the name of the input if it isn't `input.txt`.
+fn day_cell(key: DayKey, input: &Option<String>) -> String {
+    match input {
+        Some(name) => format!("{} ({})", key.day, name),
+        None => key.day.to_string(),
+    }
+}
+
+fn print_cells<const N: usize>(header: [&str; N], cells: &[[String; N]]) {
+    let mut widths = header.map(str::len);
+    for row in cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let print_row = |row: &[&str]| {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        println!("{}", line);
+    };
+
+    print_row(&header);
+    println!("{}", widths.map(|w| "-".repeat(w)).join("-|-"));
+    for row in cells {
+        print_row(&row.each_ref().map(String::as_str));
+    }
+}
+
+fn format_time(time: Duration, stats: Option<Stats>) -> String {
+    match stats {
+        Some(stats) => format!("{:?} ±{:?}", stats.median, stats.stddev),
+        None => format!("{:?}", time),
+    }
+}
@@ -0,0 +1,310 @@
+use std::path::Path;
+
+use aoc_lib::{Client, DayKey};
+
+const CARGO_TOML: &str = r#"[package]
+name = "aoc{year}-day-{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-lib = { path = "../../aoc-lib" }
+
+[build-dependencies]
+aoc-lib = { path = "../../aoc-lib" }
+
+[dev-dependencies]
+criterion = "0.3"
+
+[[bench]]
+name = "bench"
+harness = false
+"#;
+
+const BUILD_RS: &str = r#"fn main() {
+    aoc_lib::build::day();
+}
+"#;
+
+const LIB_RS: &str = r#"use aoc_lib::*;
+
+pub struct Day{day};
+
+impl AdventOfCode for Day{day} {
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input(s: &str) -> Self::Input {
+        s.lines().map(str::to_string).collect()
+    }
+
+    fn solve_1(input: &Self::Input) -> Self::Output1 {
+        input.len()
+    }
+
+    fn solve_2(input: &Self::Input) -> Self::Output2 {
+        input.len()
+    }
+}
+"#;
+
+const MAIN_RS: &str = r#"use aoc{year}_day_{day}::Day{day};
+use aoc_lib::*;
+
+aoc_setup!(Day{day});
+"#;
+
+const BENCH_RS: &str = r#"use aoc{year}_day_{day}::Day{day};
+
+aoc_lib::aoc_bench!(Day{day});
+"#;
+
+/// Adds a day to the text of a file that lists every day.
+type Register = fn(&str, DayKey) -> Result<String, String>;
+
+/// Creates the crate for a new day in the workspace at `root`, and registers it in the
+/// workspace and the runner. The input is downloaded if a client is given.
+pub fn create_day(root: &Path, key: DayKey, client: Option<&Client>) -> Result<(), String> {
+    let dir = root.join(key.crate_dir());
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // register first, so nothing is left behind if the day is already registered somewhere
+    let registrations: [(&str, Register); 3] = [
+        ("Cargo.toml", register_member),
+        ("aoc-runner/Cargo.toml", register_dependency),
+        ("aoc-runner/src/days.rs", register_day),
+    ];
+    let mut updated = vec![];
+    for (file, register) in registrations {
+        let path = root.join(file);
+        let text = std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", file, e))?;
+        updated.push((
+            path,
+            register(&text, key).map_err(|e| format!("{}: {}", file, e))?,
+        ));
+    }
+
+    for (file, text) in render(key) {
+        updated.push((dir.join(file), text));
+    }
+
+    for (path, text) in updated {
+        std::fs::create_dir_all(path.parent().unwrap()).map_err(|e| e.to_string())?;
+        std::fs::write(&path, text).map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+
+    if let Some(client) = client {
+        client
+            .cached_input(&dir.join("input.txt"), key)
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+/// The files of a new day's crate, relative to its directory.
+fn render(key: DayKey) -> Vec<(&'static str, String)> {
+    let templates = [
+        ("Cargo.toml", CARGO_TOML),
+        ("build.rs", BUILD_RS),
+        ("src/lib.rs", LIB_RS),
+        ("src/main.rs", MAIN_RS),
+        ("benches/bench.rs", BENCH_RS),
+        ("sample.txt", ""),
+        ("inputs/.gitkeep", ""),
+    ];
+    templates
+        .into_iter()
+        .map(|(file, template)| {
+            let text = template
+                .replace("{year}", &key.year.to_string())
+                .replace("{day}", &key.day.to_string());
+            (file, text)
+        })
+        .collect()
+}
+
+/// Adds the day to the workspace `members`.
+fn register_member(text: &str, key: DayKey) -> Result<String, String> {
+    let line = format!("    \"{}\",", key.crate_dir());
+    insert_sorted(text, key, &line, |line| {
+        let (year, day) = line.trim().strip_prefix('"')?.split_once("/day-")?;
+        Some(DayKey::new(
+            year.parse().ok()?,
+            day.strip_suffix("\",")?.parse().ok()?,
+        ))
+    })
+}
+
+/// Adds the day to the runner's dependencies.
+fn register_dependency(text: &str, key: DayKey) -> Result<String, String> {
+    let line = format!(
+        "{} = {{ path = \"../{}\" }}",
+        key.package_name(),
+        key.crate_dir()
+    );
+    insert_sorted(text, key, &line, |line| {
+        let key = DayKey::from_package_name(line.split_once(' ')?.0);
+        (key.year != 0 && key.day != 0).then_some(key)
+    })
+}
+
+/// Adds the day to the `days!` registry.
+fn register_day(text: &str, key: DayKey) -> Result<String, String> {
+    let line = format!(
+        "    ({0}, {1}) => aoc{0}_day_{1}::Day{1},",
+        key.year, key.day
+    );
+    insert_sorted(text, key, &line, |line| {
+        let (year, day) = line
+            .trim()
+            .strip_prefix('(')?
+            .split_once(")")?
+            .0
+            .split_once(", ")?;
+        Some(DayKey::new(year.parse().ok()?, day.parse().ok()?))
+    })
+}
+
+/// Inserts `new_line` among the lines that `key_of` finds a day in, keeping them ordered by year
+/// and day.
+fn insert_sorted(
+    text: &str,
+    key: DayKey,
+    new_line: &str,
+    key_of: impl Fn(&str) -> Option<DayKey>,
+) -> Result<String, String> {
+    let lines = text.lines().collect::<Vec<_>>();
+    let keys = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, key_of(l)?)));
+
+    let mut insert_at = None;
+    for (i, existing) in keys {
+        if existing == key {
+            return Err(format!("{} is already registered", key));
+        }
+        if existing < key || insert_at.is_none() {
+            insert_at = Some(if existing < key { i + 1 } else { i });
+        }
+    }
+
+    let mut lines = lines;
+    lines.insert(
+        insert_at.ok_or("could not find where to register the day")?,
+        new_line,
+    );
+    Ok(lines.join("\n") + "\n")
+}
+
+#[test]
+fn test_register() {
+    let members =
+        "[workspace]\nmembers = [\n    \"aoc-lib\",\n    \"2021/day-1\",\n    \"2021/day-3\",\n]\n";
+    assert_eq!(
+        "[workspace]\nmembers = [\n    \"aoc-lib\",\n    \"2021/day-1\",\n    \"2021/day-2\",\n    \"2021/day-3\",\n]\n",
+        register_member(members, DayKey::new(2021, 2)).unwrap()
+    );
+    assert!(register_member(members, DayKey::new(2021, 3)).is_err());
+
+    let days = "days! {\n    (2021, 9) => aoc2021_day_9::Day9,\n    (2021, 10) => aoc2021_day_10::Day10,\n}\n";
+    assert_eq!(
+        "days! {\n    (2021, 9) => aoc2021_day_9::Day9,\n    (2021, 10) => aoc2021_day_10::Day10,\n    (2022, 1) => aoc2022_day_1::Day1,\n}\n",
+        register_day(days, DayKey::new(2022, 1)).unwrap()
+    );
+
+    let dependencies = "[dependencies]\naoc-lib = { path = \"../aoc-lib\" }\naoc2021-day-2 = { path = \"../2021/day-2\" }\n";
+    assert_eq!(
+        "[dependencies]\naoc-lib = { path = \"../aoc-lib\" }\naoc2021-day-1 = { path = \"../2021/day-1\" }\naoc2021-day-2 = { path = \"../2021/day-2\" }\n",
+        register_dependency(dependencies, DayKey::new(2021, 1)).unwrap()
+    );
+}
+
+#[test]
+fn test_render_matches_existing_days() {
+    let root = crate::workspace_path("");
+    let mut days = 0;
+    for entry in std::fs::read_dir(root.join("2021")).unwrap() {
+        let path = entry.unwrap().path();
+        let Some(day) = path
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day-")?.parse().ok())
+        else {
+            continue;
+        };
+        let key = DayKey::new(2021, day);
+        days += 1;
+
+        // everything else in a day's crate should come from a template
+        let rendered = render(key);
+        for file in crate_files(&path, "") {
+            let added_by_day = file == "input.txt"
+                || file.starts_with("sample")
+                || ["src/", "benches/", "examples/", "inputs/"]
+                    .iter()
+                    .any(|dir| file.starts_with(dir));
+            assert!(
+                added_by_day || rendered.iter().any(|(f, _)| *f == file),
+                "{}/{} is not created by the scaffold",
+                key.crate_dir(),
+                file
+            );
+        }
+
+        for (file, text) in rendered {
+            let existing = std::fs::read_to_string(path.join(file))
+                .unwrap_or_else(|e| panic!("{}/{}: {}", key.crate_dir(), file, e));
+            // days add their own dependencies and code, but keep everything the templates set up
+            let missing = match file {
+                "build.rs" | "benches/bench.rs" => (existing != text).then_some(text.as_str()),
+                "Cargo.toml" | "src/main.rs" => text
+                    .lines()
+                    .filter(|line| !line.starts_with("aoc_setup!"))
+                    .find(|line| !existing.lines().any(|l| l == *line)),
+                _ => None,
+            };
+            if let Some(missing) = missing {
+                panic!("{}/{} lacks {:?}", key.crate_dir(), file, missing);
+            }
+        }
+        assert!(
+            std::fs::read_to_string(path.join("src/main.rs"))
+                .unwrap()
+                .contains("aoc_setup!("),
+            "{} doesn't call aoc_setup!",
+            key.crate_dir()
+        );
+    }
+    assert!(days > 0, "no days found");
+
+    let files = render(DayKey::new(2022, 1));
+    let cargo_toml = &files
+        .iter()
+        .find(|(file, _)| *file == "Cargo.toml")
+        .unwrap()
+        .1;
+    assert!(cargo_toml.contains("name = \"aoc2022-day-1\""));
+    assert!(cargo_toml.contains("[build-dependencies]\naoc-lib = { path = \"../../aoc-lib\" }"));
+}
+
+/// The paths of the files under `dir`, relative to the crate directory it is in.
+#[cfg(test)]
+fn crate_files(dir: &Path, prefix: &str) -> Vec<String> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let entry = entry.unwrap();
+        let name = format!("{}{}", prefix, entry.file_name().to_string_lossy());
+        if entry.file_type().unwrap().is_dir() {
+            files.extend(crate_files(&entry.path(), &format!("{}/", name)));
+        } else {
+            files.push(name);
+        }
+    }
+    files
+}